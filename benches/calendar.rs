@@ -0,0 +1,42 @@
+use std::hint::black_box;
+
+use budget_planner::{BudgetYear, CalendarBuilder, Ownership, Period};
+use chrono::Month::*;
+use chrono::Weekday;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn one_year(c: &mut Criterion) {
+    c.bench_function("budget year 2024", |b| {
+        b.iter(|| BudgetYear::new(black_box(2024)).unwrap())
+    });
+}
+
+fn many_years(c: &mut Criterion) {
+    c.bench_function("budget years 1900 to 2100", |b| {
+        b.iter(|| {
+            for year in 1900..=2100 {
+                black_box(BudgetYear::new(black_box(year)).unwrap());
+            }
+        })
+    });
+}
+
+fn custom_calendar(c: &mut Criterion) {
+    let calendar = CalendarBuilder::new()
+        .week_start(Weekday::Sun)
+        .ownership(Ownership::LastDay)
+        .periods(vec![
+            Period::new("Winter", vec![January, February, March]),
+            Period::new("Spring", vec![April, May, June]),
+            Period::new("Autumn", vec![September, October, November, December]),
+        ])
+        .build()
+        .unwrap();
+
+    c.bench_function("custom calendar 2024", |b| {
+        b.iter(|| calendar.year(black_box(2024)).unwrap())
+    });
+}
+
+criterion_group!(benches, one_year, many_years, custom_calendar);
+criterion_main!(benches);
@@ -0,0 +1,104 @@
+#ifndef BUDGET_PLANNER_H
+#define BUDGET_PLANNER_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// What a function of the C API returns.
+typedef enum BpStatus {
+  BP_STATUS_OK = 0,
+  // A pointer given to the function is `NULL`.
+  BP_STATUS_NULL_POINTER,
+  // The options are not valid UTF-8 or JSON, or break the rules of the budget periods.
+  BP_STATUS_INVALID_OPTIONS,
+  // The year is out of the range supported by chrono.
+  BP_STATUS_YEAR_OUT_OF_RANGE,
+  // The month or week index is past the last one.
+  BP_STATUS_INDEX_OUT_OF_RANGE,
+  // The date doesn't exist, or isn't part of any budget month of the year.
+  BP_STATUS_NOT_FOUND,
+} BpStatus;
+
+// A year split into budget months, created by [`bp_calendar_new`] and freed by [`bp_calendar_free`].
+typedef struct BpCalendar BpCalendar;
+
+// A calendar date.
+typedef struct BpDate {
+  int32_t year;
+  // From 1 to 12.
+  uint32_t month;
+  // From 1 to 31.
+  uint32_t day;
+} BpDate;
+
+// A budget week, both days included.
+typedef struct BpWeek {
+  struct BpDate start;
+  struct BpDate end;
+} BpWeek;
+
+// Splits `year` and stores the calendar in `out`.
+//
+// `options` is `NULL` or a JSON object with the options of the config file (but `timezone`),
+// like `{"week_start": "Sun"}`.
+//
+// # Safety
+//
+// `options` must be `NULL` or a valid C string, `out` a valid pointer.
+enum BpStatus bp_calendar_new(int32_t year, const char *options, struct BpCalendar **out);
+
+// Frees a calendar created by [`bp_calendar_new`], `NULL` is ignored.
+//
+// # Safety
+//
+// `calendar` must come from [`bp_calendar_new`] and not be used afterwards.
+void bp_calendar_free(struct BpCalendar *calendar);
+
+// The number of budget months, 0 for `NULL`.
+//
+// # Safety
+//
+// `calendar` must be `NULL` or come from [`bp_calendar_new`].
+size_t bp_calendar_month_count(const struct BpCalendar *calendar);
+
+// The name of a budget month, `NULL` when out of range. It lives as long as the calendar.
+//
+// # Safety
+//
+// `calendar` must be `NULL` or come from [`bp_calendar_new`].
+const char *bp_calendar_month_name(const struct BpCalendar *calendar, size_t month);
+
+// The number of weeks of a budget month, 0 when out of range.
+//
+// # Safety
+//
+// `calendar` must be `NULL` or come from [`bp_calendar_new`].
+size_t bp_calendar_week_count(const struct BpCalendar *calendar, size_t month);
+
+// Stores the first and last days of a week of a budget month in `out`.
+//
+// # Safety
+//
+// `calendar` must be `NULL` or come from [`bp_calendar_new`], `out` must be `NULL` or valid.
+enum BpStatus bp_calendar_week(const struct BpCalendar *calendar,
+                               size_t month,
+                               size_t week,
+                               struct BpWeek *out);
+
+// Stores the indexes of the budget month and of the week (in that month) of `date`.
+//
+// # Safety
+//
+// `calendar` must be `NULL` or come from [`bp_calendar_new`], `month` and `week`
+// must be `NULL` or valid.
+enum BpStatus bp_calendar_lookup(const struct BpCalendar *calendar,
+                                 struct BpDate date,
+                                 size_t *month,
+                                 size_t *week);
+
+#endif  /* BUDGET_PLANNER_H */
@@ -0,0 +1,359 @@
+use core::ops::RangeBounds;
+
+use alloc::vec::Vec;
+
+use chrono::Weekday;
+
+use crate::civil::CivilWeekday;
+use crate::error::CalendarError;
+use crate::month::Months;
+use crate::ownership::Ownership;
+use crate::periods::{Period, Periods};
+use crate::strategy::{PeriodStrategy, Strategy};
+use crate::week::Weeks;
+use crate::year::BudgetYear;
+
+/// Builds a [`Calendar`], checking every option at once.
+///
+/// ```
+/// use budget_planner::{CalendarBuilder, Ownership, Period};
+/// use chrono::Month::*;
+/// use chrono::Weekday;
+///
+/// let year = CalendarBuilder::new()
+///     .week_start(Weekday::Sun)
+///     .ownership(Ownership::FirstDay)
+///     .periods(vec![
+///         Period::new("Winter", vec![January, February, March]),
+///         Period::new("Spring", vec![April, May, June]),
+///     ])
+///     .build_year(2024)
+///     .unwrap();
+///
+/// assert_eq!(year.months().len(), 2);
+/// assert_eq!(year.weeks()[1].start_day(), Weekday::Sun);
+/// ```
+///
+/// It can also be deserialized from the options of the config file (but `timezone`),
+/// every option being optional:
+///
+/// ```json
+/// {
+///   "week_start": "Sun",
+///   "ownership": "first-day",
+///   "strategy": "monthly",
+///   "periods": [{ "name": "Winter", "months": ["January", "February"] }]
+/// }
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct CalendarBuilder {
+    week_start: Option<Weekday>,
+    ownership: Option<Ownership>,
+    strategy: Option<Strategy>,
+    periods: Option<Vec<Period>>,
+}
+
+impl CalendarBuilder {
+    /// A builder for the default calendar: weeks starting on Monday, the majority
+    /// ownership rule and one budget month per calendar month.
+    pub fn new() -> CalendarBuilder {
+        CalendarBuilder::default()
+    }
+
+    /// The weekday every week (but the first one of the year) starts on.
+    pub fn week_start(mut self, week_start: impl CivilWeekday) -> CalendarBuilder {
+        self.week_start = Some(week_start.to_weekday());
+        self
+    }
+
+    /// How a week overlapping two calendar months is given to one of them.
+    pub fn ownership(mut self, ownership: Ownership) -> CalendarBuilder {
+        self.ownership = Some(ownership);
+        self
+    }
+
+    /// How the weeks are given to calendar months, see [`Strategy`].
+    pub fn strategy(mut self, strategy: Strategy) -> CalendarBuilder {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    /// The budget periods to use instead of the calendar months.
+    pub fn periods(mut self, periods: Vec<Period>) -> CalendarBuilder {
+        self.periods = Some(periods);
+        self
+    }
+
+    /// Checks the options, and keeps them in a [`Calendar`] able to split any year.
+    pub fn build(&self) -> Result<Calendar, CalendarError> {
+        let periods: Periods = match &self.periods {
+            Some(periods) => Periods::new(periods.clone())?,
+            None => Periods::default(),
+        };
+
+        Ok(Calendar {
+            week_start: self.week_start.unwrap_or(Weekday::Mon),
+            ownership: self.ownership.unwrap_or_default(),
+            strategy: self.strategy.unwrap_or_default(),
+            periods,
+        })
+    }
+
+    /// Checks the options and splits `year`.
+    pub fn build_year(&self, year: i32) -> Result<BudgetYear, CalendarError> {
+        self.build()?.year(year)
+    }
+}
+
+/// The options used to split years into budget months, see [`CalendarBuilder`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Calendar {
+    week_start: Weekday,
+    ownership: Ownership,
+    strategy: Strategy,
+    periods: Periods,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Calendar {
+            week_start: Weekday::Mon,
+            ownership: Ownership::default(),
+            strategy: Strategy::default(),
+            periods: Periods::default(),
+        }
+    }
+}
+
+impl Calendar {
+    /// The weekday every week (but the first one of the year) starts on.
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// How a week overlapping two calendar months is given to one of them.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+
+    /// How the weeks are given to calendar months.
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    /// The budget periods each year is split into.
+    pub fn periods(&self) -> &Periods {
+        &self.periods
+    }
+
+    /// Splits `year` into budget weeks and months.
+    pub fn year(&self, year: i32) -> Result<BudgetYear, CalendarError> {
+        BudgetYear::with_calendar(year, self, &self.strategy)
+    }
+
+    /// Splits `year` into budget weeks, grouped into months by `strategy` instead of
+    /// the strategy of the calendar.
+    pub fn year_with<S: PeriodStrategy + ?Sized>(
+        &self,
+        year: i32,
+        strategy: &S,
+    ) -> Result<BudgetYear, CalendarError> {
+        BudgetYear::with_calendar(year, self, strategy)
+    }
+
+    /// Lazily yields the budget weeks of `years`, which can be open-ended.
+    ///
+    /// ```
+    /// use budget_planner::Calendar;
+    /// use chrono::{Datelike, Weekday};
+    ///
+    /// let calendar = Calendar::default();
+    /// let mondays = calendar
+    ///     .weeks(2024..)
+    ///     .unwrap()
+    ///     .take_while(|week| week.start_date().year() < 2034)
+    ///     .filter(|week| week.start_day() == Weekday::Mon)
+    ///     .count();
+    ///
+    /// assert_eq!(mondays, 522);
+    /// ```
+    pub fn weeks(&self, years: impl RangeBounds<i32>) -> Result<Weeks, CalendarError> {
+        Weeks::of_years(years, self.week_start)
+    }
+
+    /// Lazily yields the budget months of `years` with their calendar year.
+    ///
+    /// `years` can be open-ended, like `2024..`.
+    pub fn months(&self, years: impl RangeBounds<i32>) -> Result<Months<'_, Weeks>, CalendarError> {
+        Ok(Months::new(self.weeks(years)?, self, self.strategy))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Month::*;
+    use chrono::{Datelike, NaiveDate};
+
+    use super::*;
+    use crate::month::Month;
+    use crate::week::Week;
+
+    #[test]
+    fn build_default_calendar() {
+        assert_eq!(CalendarBuilder::new().build(), Ok(Calendar::default()));
+    }
+
+    #[test]
+    fn build_invalid_periods() {
+        let builder = CalendarBuilder::new().periods(vec![Period::new("Nothing", vec![])]);
+
+        assert!(matches!(
+            builder.build(),
+            Err(CalendarError::InvalidPeriods(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_options() {
+        let builder: CalendarBuilder =
+            serde_json::from_str(r#"{"week_start": "Sun", "strategy": "4-4-5"}"#).unwrap();
+
+        assert_eq!(
+            builder,
+            CalendarBuilder::new()
+                .week_start(Weekday::Sun)
+                .strategy(Strategy::FourFourFive)
+        );
+        assert!(serde_json::from_str::<CalendarBuilder>(r#"{"timezone": "UTC"}"#).is_err());
+    }
+
+    #[test]
+    fn build_year_out_of_range() {
+        assert_eq!(
+            CalendarBuilder::new().build_year(i32::MIN),
+            Err(CalendarError::YearOutOfRange(i32::MIN))
+        );
+    }
+
+    #[test]
+    fn weeks_starting_on_sunday() {
+        let year = CalendarBuilder::new()
+            .week_start(Weekday::Sun)
+            .build_year(2024)
+            .unwrap();
+
+        // 2024-01-01 is a Monday, the first week lasts until Saturday.
+        assert_eq!(
+            year.weeks()[0].end_date(),
+            NaiveDate::from_ymd_opt(2024, 1, 6).unwrap()
+        );
+        assert!(year.weeks()[1..]
+            .iter()
+            .all(|week| week.start_day() == Weekday::Sun));
+        assert_eq!(year.weeks().len(), 53);
+    }
+
+    #[test]
+    fn ownership_first_day() {
+        let year = CalendarBuilder::new()
+            .ownership(Ownership::FirstDay)
+            .build_year(2024)
+            .unwrap();
+
+        // The week from Jan 29 to Feb 04 starts in January.
+        let january = &year.months()[0];
+        assert_eq!(january.weeks().len(), 5);
+        assert_eq!(january.end_date(), NaiveDate::from_ymd_opt(2024, 2, 4));
+    }
+
+    #[test]
+    fn ownership_last_day() {
+        let year = CalendarBuilder::new()
+            .ownership(Ownership::LastDay)
+            .build_year(2024)
+            .unwrap();
+
+        // The week from Apr 29 to May 05 ends in May.
+        let april = &year.months()[3];
+        assert_eq!(april.end_date(), NaiveDate::from_ymd_opt(2024, 4, 28));
+    }
+
+    #[test]
+    fn every_week_is_owned_once() {
+        for ownership in [Ownership::Majority, Ownership::FirstDay, Ownership::LastDay] {
+            for week_start in [Weekday::Mon, Weekday::Wed, Weekday::Sun] {
+                let year = CalendarBuilder::new()
+                    .week_start(week_start)
+                    .ownership(ownership)
+                    .build_year(2023)
+                    .unwrap();
+
+                let owned: usize = year.months().iter().map(|month| month.weeks().len()).sum();
+                assert_eq!(owned, year.weeks().len());
+            }
+        }
+    }
+
+    #[test]
+    fn weeks_of_open_ended_years() {
+        let calendar = Calendar::default();
+
+        let weeks: Vec<Week> = calendar
+            .weeks(2023..)
+            .unwrap()
+            .take_while(|week| week.start_date().year() < 2025)
+            .collect();
+
+        assert_eq!(weeks.len(), 106);
+        assert_eq!(weeks[..53], *calendar.year(2023).unwrap().weeks());
+        assert_eq!(weeks[53..], *calendar.year(2024).unwrap().weeks());
+    }
+
+    #[test]
+    fn months_of_a_range_of_years() {
+        let calendar = CalendarBuilder::new()
+            .periods(vec![
+                Period::new("Winter", vec![January, February, March]),
+                Period::new("Summer", vec![July, August, September]),
+            ])
+            .build()
+            .unwrap();
+
+        let months: Vec<(i32, Month)> = calendar.months(2023..=2024).unwrap().collect();
+
+        assert_eq!(months.len(), 4);
+        assert_eq!(months[2].0, 2024);
+        assert_eq!(months[2].1, calendar.year(2024).unwrap().months()[0]);
+    }
+
+    #[test]
+    fn months_until_the_last_supported_year() {
+        let last_year = NaiveDate::MAX.year();
+
+        let months: Vec<(i32, Month)> = Calendar::default()
+            .months(last_year - 1..)
+            .unwrap()
+            .collect();
+
+        assert_eq!(months.len(), 24);
+        assert_eq!(months.last().unwrap().1.end_date(), Some(NaiveDate::MAX));
+    }
+
+    #[test]
+    fn weeks_out_of_range() {
+        assert!(Calendar::default().weeks(..=i32::MAX).is_err());
+    }
+
+    #[test]
+    fn periods_of_the_calendar() {
+        let calendar = CalendarBuilder::new()
+            .periods(vec![Period::new("Winter", vec![January, February])])
+            .build()
+            .unwrap();
+
+        assert_eq!(calendar.periods().iter().count(), 1);
+    }
+}
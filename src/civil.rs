@@ -0,0 +1,226 @@
+//! The date and weekday types of chrono, time and jiff.
+//!
+//! The calendar works with chrono, but its entry points take any [`CivilDate`] and
+//! [`CivilWeekday`], and give dates back in the type of the caller with the `_as` accessors:
+//!
+//! ```
+//! use budget_planner::{Week, Weeks};
+//! use chrono::{NaiveDate, Weekday};
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 1, 30).unwrap();
+//! let week: Week = Weeks::of_years(2024..=2024, Weekday::Mon)
+//!     .unwrap()
+//!     .find(|week| week.contains(date))
+//!     .unwrap();
+//!
+//! assert_eq!(week.start_date_as::<NaiveDate>(), Some(week.start_date()));
+//! ```
+
+#[cfg(any(feature = "time", feature = "jiff"))]
+use chrono::Datelike;
+use chrono::{NaiveDate, Weekday};
+
+/// A date without time zone, converted to and from chrono.
+pub trait CivilDate: Copy {
+    /// `None` when `self` is out of the range of chrono.
+    fn to_naive_date(self) -> Option<NaiveDate>;
+
+    /// `None` when `date` is out of the range of `Self`.
+    fn from_naive_date(date: NaiveDate) -> Option<Self>;
+}
+
+/// A day of the week, converted to and from chrono.
+pub trait CivilWeekday: Copy {
+    fn to_weekday(self) -> Weekday;
+
+    fn from_weekday(weekday: Weekday) -> Self;
+}
+
+impl CivilDate for NaiveDate {
+    fn to_naive_date(self) -> Option<NaiveDate> {
+        Some(self)
+    }
+
+    fn from_naive_date(date: NaiveDate) -> Option<Self> {
+        Some(date)
+    }
+}
+
+impl CivilWeekday for Weekday {
+    fn to_weekday(self) -> Weekday {
+        self
+    }
+
+    fn from_weekday(weekday: Weekday) -> Self {
+        weekday
+    }
+}
+
+/// Weekdays counted from Monday, as chrono, time and jiff all do.
+#[cfg(any(feature = "time", feature = "jiff"))]
+fn weekday_from_monday(days: u8) -> Weekday {
+    Weekday::try_from(days).expect("a weekday is less than 7 days from Monday")
+}
+
+#[cfg(feature = "time")]
+impl CivilDate for time::Date {
+    fn to_naive_date(self) -> Option<NaiveDate> {
+        // With its large-dates feature, time supports more years than chrono.
+        NaiveDate::from_yo_opt(self.year(), u32::from(self.ordinal()))
+    }
+
+    fn from_naive_date(date: NaiveDate) -> Option<Self> {
+        time::Date::from_ordinal_date(date.year(), u16::try_from(date.ordinal()).ok()?).ok()
+    }
+}
+
+#[cfg(feature = "time")]
+impl CivilWeekday for time::Weekday {
+    fn to_weekday(self) -> Weekday {
+        weekday_from_monday(self.number_days_from_monday())
+    }
+
+    fn from_weekday(weekday: Weekday) -> Self {
+        time::Weekday::Monday.nth_next(weekday.num_days_from_monday() as u8)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl CivilDate for jiff::civil::Date {
+    fn to_naive_date(self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(
+            i32::from(self.year()),
+            self.month() as u32,
+            self.day() as u32,
+        )
+    }
+
+    fn from_naive_date(date: NaiveDate) -> Option<Self> {
+        jiff::civil::Date::new(
+            i16::try_from(date.year()).ok()?,
+            date.month() as i8,
+            date.day() as i8,
+        )
+        .ok()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl CivilWeekday for jiff::civil::Weekday {
+    fn to_weekday(self) -> Weekday {
+        weekday_from_monday(self.to_monday_zero_offset() as u8)
+    }
+
+    fn from_weekday(weekday: Weekday) -> Self {
+        jiff::civil::Weekday::Monday.wrapping_add(weekday.num_days_from_monday() as i64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(any(feature = "time", feature = "jiff"))]
+    const WEEKDAYS: [Weekday; 7] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+
+    #[test]
+    fn chrono_is_left_as_is() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        assert_eq!(NaiveDate::from_naive_date(date), Some(date));
+        assert_eq!(date.to_naive_date(), Some(date));
+        assert_eq!(Weekday::from_weekday(Weekday::Sun), Weekday::Sun);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_dates() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let time_date = time::Date::from_calendar_date(2024, time::Month::February, 29).unwrap();
+
+        assert_eq!(time::Date::from_naive_date(date), Some(time_date));
+        assert_eq!(time_date.to_naive_date(), Some(date));
+        // With large-dates, every chrono date fits in time.
+        assert_eq!(
+            time::Date::from_naive_date(NaiveDate::MAX).and_then(CivilDate::to_naive_date),
+            Some(NaiveDate::MAX)
+        );
+        for weekday in WEEKDAYS {
+            assert_eq!(time::Weekday::from_weekday(weekday).to_weekday(), weekday);
+        }
+        assert_eq!(
+            time::Weekday::from_weekday(Weekday::Sun),
+            time::Weekday::Sunday
+        );
+    }
+
+    #[cfg(all(feature = "time", feature = "alloc"))]
+    #[test]
+    fn split_with_time() {
+        use crate::calendar::CalendarBuilder;
+
+        let calendar = CalendarBuilder::new()
+            .week_start(time::Weekday::Sunday)
+            .build()
+            .unwrap();
+        let year = calendar.year(2024).unwrap();
+        let date = time::Date::from_calendar_date(2024, time::Month::January, 30).unwrap();
+
+        let week = year.week_of(date).unwrap();
+
+        assert_eq!(
+            week.start_date_as(),
+            time::Date::from_calendar_date(2024, time::Month::January, 28).ok()
+        );
+        assert_eq!(week.start_day_as::<time::Weekday>(), time::Weekday::Sunday);
+        // 4 of its days are in January.
+        assert_eq!(year.month_of(date).unwrap().name(), "January");
+        assert!(year.month_of(date).unwrap().end_date_as::<time::Date>() > Some(date));
+    }
+
+    #[cfg(all(feature = "time", feature = "alloc"))]
+    #[test]
+    fn time_dates_out_of_range() {
+        use crate::year::BudgetYear;
+
+        // The tests enable the large-dates feature of time, up to the year 999,999.
+        let date = time::Date::MAX;
+        let year = BudgetYear::new(2024).unwrap();
+
+        assert_eq!(date.to_naive_date(), None);
+        assert!(!year.weeks()[0].contains(date));
+        assert!(!year.months()[0].contains(date));
+        assert_eq!(year.week_of(date), None);
+        assert_eq!(year.month_of(date), None);
+        assert_eq!(year.id_of(date), None);
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_dates() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let jiff_date = jiff::civil::date(2024, 2, 29);
+
+        assert_eq!(jiff::civil::Date::from_naive_date(date), Some(jiff_date));
+        assert_eq!(jiff_date.to_naive_date(), Some(date));
+        assert_eq!(jiff::civil::Date::from_naive_date(NaiveDate::MAX), None);
+        for weekday in WEEKDAYS {
+            assert_eq!(
+                jiff::civil::Weekday::from_weekday(weekday).to_weekday(),
+                weekday
+            );
+        }
+        assert_eq!(
+            jiff::civil::Weekday::from_weekday(Weekday::Sun),
+            jiff::civil::Weekday::Sunday
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use chrono::{NaiveDate, Utc, Weekday};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::calendar::{Calendar, CalendarBuilder};
+use crate::compare;
+use crate::csv::{self, CsvRenderer, Quoting};
+use crate::dates::{self, Clock};
+use crate::ics::IcsRenderer;
+use crate::lookup;
+use crate::markdown::MarkdownRenderer;
+use crate::month::Month;
+use crate::ownership::Ownership;
+use crate::plan::{Envelope, Plan, SCHEMA_VERSION};
+use crate::render::{JsonRenderer, Renderer, Renderers};
+use crate::settings::Settings;
+use crate::similar;
+use crate::strategy::Strategy;
+use crate::validate::{validate, Violation};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct Config {
+    #[arg(short, long, required = true, allow_hyphen_values = true)]
+    pub year: Option<i32>,
+
+    /// A TOML file with the calendar options: budget periods, week start, ownership rule and timezone
+    #[arg(short, long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// The first day of the weeks, e.g. Mon or Sunday [default: the `week_start` setting, or Mon]
+    #[arg(long, global = true)]
+    pub week_start: Option<Weekday>,
+
+    /// How a week overlapping two months is given to one of them: majority, first-day or last-day [default: the `ownership` setting, or majority]
+    #[arg(long, global = true)]
+    pub ownership: Option<Ownership>,
+
+    /// How the weeks are grouped into months: monthly (with the ownership rule), 4-4-5, 4-5-4 or 5-4-4 [default: the `strategy` setting, or monthly]
+    #[arg(long, global = true)]
+    pub strategy: Option<Strategy>,
+
+    /// The timezone used to know what today is, e.g. Europe/Paris [default: the `timezone` setting, or the local timezone]
+    #[arg(long, global = true, value_parser = dates::parse_timezone)]
+    pub tz: Option<chrono_tz::Tz>,
+
+    /// The output format of the budget months: json, csv, ics or markdown
+    #[arg(long, default_value = "json")]
+    pub format: String,
+
+    /// With the json format, wrap the budget months in an envelope with the schema and tool versions and the calendar options
+    #[arg(long)]
+    pub envelope: bool,
+
+    /// With the csv format, the character between the fields, anything but a double quote or a line break
+    #[arg(long, default_value_t = ',', value_parser = csv::parse_delimiter)]
+    pub delimiter: char,
+
+    /// With the csv format, leave out the names of the columns
+    #[arg(long)]
+    pub no_header: bool,
+
+    /// With the csv format, which fields are quoted: necessary, always or never
+    #[arg(long, default_value_t = Quoting::Necessary)]
+    pub quoting: Quoting,
+
+    /// With the ics format, also add an event per budget week
+    #[arg(long)]
+    pub weeks: bool,
+
+    /// With the markdown format, a single table with a row per budget month instead of a table per month
+    #[arg(long)]
+    pub compact: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Compare the budget months of two years
+    Compare(CompareArgs),
+    /// List the years sharing the budget months of a year
+    Similar(SimilarArgs),
+    /// Find the budget week and month of a date
+    Lookup(LookupArgs),
+    /// Check a budget plan, like the JSON output edited by hand
+    Validate(ValidateArgs),
+    /// Print the JSON Schema of the plans written with `--year` and read by `validate`
+    Schema,
+}
+
+#[derive(Args, Debug)]
+pub struct CompareArgs {
+    /// The two years to compare, e.g. `--year 2024 --year 2025`
+    #[arg(short, long, required = true, allow_hyphen_values = true)]
+    pub year: Vec<i32>,
+
+    #[arg(short, long, value_enum, default_value_t = CompareFormat::Table)]
+    pub format: CompareFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct SimilarArgs {
+    #[arg(short, long, allow_hyphen_values = true)]
+    pub year: i32,
+
+    /// First year to look at [default: 100 years before `--year`]
+    #[arg(long, allow_hyphen_values = true)]
+    pub from: Option<i32>,
+
+    /// Last year to look at [default: 100 years after `--year`]
+    #[arg(long, allow_hyphen_values = true)]
+    pub to: Option<i32>,
+
+    /// Also require each month to start and end on the same day offsets
+    #[arg(long)]
+    pub offsets: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct LookupArgs {
+    /// `today`, `yesterday`, `tomorrow`, a date formatted as YYYY-MM-DD, or the id of a budget
+    /// week or month (2024-M02-W3, 2024-M02) for its first day
+    #[arg(short, long, default_value = "today", allow_hyphen_values = true)]
+    pub date: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ValidateArgs {
+    /// The JSON file of the plan, as written by `budget_planner --year`
+    pub file: PathBuf,
+}
+
+/// The output format of `compare`, the other commands use the renderers of `--format`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum CompareFormat {
+    Table,
+    Json,
+}
+
+impl Config {
+    fn settings(&self) -> Result<Settings, Box<dyn Error>> {
+        match &self.config {
+            Some(path) => Settings::load(path),
+            None => Ok(Settings::default()),
+        }
+    }
+
+    /// The built-in output formats, with the options given on the command line.
+    fn renderers(&self) -> Renderers {
+        let mut renderers = Renderers::new();
+        renderers.register(
+            "json",
+            JsonRenderer {
+                envelope: self.envelope,
+            },
+        );
+        renderers.register(
+            "csv",
+            CsvRenderer {
+                delimiter: self.delimiter,
+                header: !self.no_header,
+                quoting: self.quoting,
+            },
+        );
+        renderers.register(
+            "ics",
+            IcsRenderer {
+                weeks: self.weeks,
+                stamp: Some(Utc::now().naive_utc()),
+            },
+        );
+        renderers.register(
+            "markdown",
+            MarkdownRenderer {
+                compact: self.compact,
+            },
+        );
+
+        renderers
+    }
+}
+
+/// The options of the config file, overridden by the ones given on the command line.
+impl TryFrom<&Config> for CalendarBuilder {
+    type Error = Box<dyn Error>;
+
+    fn try_from(config: &Config) -> Result<Self, Self::Error> {
+        let mut builder: CalendarBuilder = config.settings()?.calendar_builder();
+
+        if let Some(week_start) = config.week_start {
+            builder = builder.week_start(week_start);
+        }
+        if let Some(ownership) = config.ownership {
+            builder = builder.ownership(ownership);
+        }
+        if let Some(strategy) = config.strategy {
+            builder = builder.strategy(strategy);
+        }
+
+        Ok(builder)
+    }
+}
+
+pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let calendar: Calendar = CalendarBuilder::try_from(&config)?.build()?;
+
+    match &config.command {
+        Some(Command::Compare(args)) => run_compare(args, &calendar),
+        Some(Command::Similar(args)) => run_similar(args, &calendar),
+        Some(Command::Lookup(args)) => {
+            let clock = Clock::new(match config.tz {
+                Some(tz) => Some(tz),
+                None => config.settings()?.timezone()?,
+            });
+            run_lookup(args, &calendar, &clock)
+        }
+        Some(Command::Validate(args)) => run_validate(args, &config, calendar),
+        Some(Command::Schema) => {
+            println!("{}", serde_json::to_string_pretty(&Plan::json_schema())?);
+
+            Ok(())
+        }
+        None => {
+            let year: i32 = config.year.ok_or("a year is required")?;
+            let months: Vec<Month> = calendar.year(year)?.into_months();
+
+            let renderers: Renderers = config.renderers();
+            let renderer: &dyn Renderer = renderers.get(&config.format).ok_or_else(|| {
+                format!(
+                    "unknown format {}, expected {}",
+                    config.format,
+                    renderers.names().collect::<Vec<&str>>().join(", ")
+                )
+            })?;
+
+            let plan = Envelope::new(vec![year], &calendar, months);
+            renderer.render(&plan, &mut io::stdout().lock())?;
+
+            Ok(())
+        }
+    }
+}
+
+fn run_compare(args: &CompareArgs, calendar: &Calendar) -> Result<(), Box<dyn Error>> {
+    let [first, second] = args.year[..] else {
+        return Err("compare expects exactly two years, e.g. `--year 2024 --year 2025`".into());
+    };
+
+    let comparison = compare::compare_years(first, second, calendar)?;
+
+    match args.format {
+        CompareFormat::Table => print!("{}", comparison.to_table()),
+        CompareFormat::Json => println!("{}", serde_json::to_string(&comparison)?),
+    }
+
+    Ok(())
+}
+
+fn run_similar(args: &SimilarArgs, calendar: &Calendar) -> Result<(), Box<dyn Error>> {
+    let default_range = similar::default_range(args.year);
+    let from: i32 = args.from.unwrap_or(*default_range.start());
+    let to: i32 = args.to.unwrap_or(*default_range.end());
+
+    for year in similar::similar_years(args.year, from..=to, args.offsets, calendar)? {
+        println!("{}", year);
+    }
+
+    Ok(())
+}
+
+fn run_lookup(args: &LookupArgs, calendar: &Calendar, clock: &Clock) -> Result<(), Box<dyn Error>> {
+    let date: NaiveDate = clock.resolve(&args.date, calendar)?;
+
+    let j = serde_json::to_string(&lookup::lookup(date, calendar)?)?;
+    println!("{}", j);
+
+    Ok(())
+}
+
+fn run_validate(
+    args: &ValidateArgs,
+    config: &Config,
+    calendar: Calendar,
+) -> Result<(), Box<dyn Error>> {
+    let path = args.file.display();
+    let content: String =
+        fs::read_to_string(&args.file).map_err(|e| format!("cannot read {}: {}", path, e))?;
+    let plan: Plan = serde_json::from_str(&content)
+        .map_err(|e| format!("{}: not a budget plan, {}", path, e))?;
+
+    // Without calendar options on the command line, a plan is checked against its own.
+    let calendar: Calendar = match plan.envelope() {
+        Some(envelope) if envelope.schema_version > SCHEMA_VERSION => {
+            return Err(format!(
+                "{}: schema version {} is newer than the supported {}",
+                path, envelope.schema_version, SCHEMA_VERSION
+            )
+            .into());
+        }
+        Some(envelope)
+            if config.config.is_none()
+                && config.week_start.is_none()
+                && config.ownership.is_none()
+                && config.strategy.is_none() =>
+        {
+            envelope.calendar_builder().build()?
+        }
+        _ => calendar,
+    };
+
+    let violations: Vec<Violation> = validate(plan.months(), &calendar);
+    if violations.is_empty() {
+        println!("{} is a valid budget plan", path);
+        return Ok(());
+    }
+
+    for violation in &violations {
+        println!("{}: {}", path, violation);
+    }
+
+    Err(format!("{} violation(s) found in {}", violations.len(), path).into())
+}
@@ -0,0 +1,259 @@
+use std::fmt::Write;
+
+use chrono::{Month as ChronoMonth, NaiveDate};
+use serde::Serialize;
+
+use crate::calendar::Calendar;
+use crate::error::CalendarError;
+use crate::month::Month;
+use crate::periods::Period;
+use crate::week::{first_day_month, last_day_month};
+
+/// The budget months of two years, aligned month by month.
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct YearComparison {
+    years: [i32; 2],
+    months: Vec<MonthComparison>,
+}
+
+/// Every pair holds the value for the first year, then the second one.
+/// The differences are always `second - first`.
+#[derive(Debug, PartialEq, Serialize)]
+struct MonthComparison {
+    name: String,
+    weeks: [usize; 2],
+    week_difference: i64,
+    start_dates: [Option<NaiveDate>; 2],
+    start_shift: Option<i64>,
+    end_dates: [Option<NaiveDate>; 2],
+    end_shift: Option<i64>,
+    days: [i64; 2],
+    day_difference: i64,
+}
+
+pub(crate) fn compare_years(
+    first: i32,
+    second: i32,
+    calendar: &Calendar,
+) -> Result<YearComparison, CalendarError> {
+    let first_year = calendar.year(first)?;
+    let second_year = calendar.year(second)?;
+
+    let months = calendar
+        .periods()
+        .iter()
+        .zip(first_year.months())
+        .zip(second_year.months())
+        .map(|((period, a), b)| compare_months(period, (first, a), (second, b)))
+        .collect();
+
+    Ok(YearComparison {
+        years: [first, second],
+        months,
+    })
+}
+
+fn compare_months(
+    period: &Period,
+    (first, a): (i32, &Month),
+    (second, b): (i32, &Month),
+) -> MonthComparison {
+    let start_dates = [a.start_date(), b.start_date()];
+    let end_dates = [a.end_date(), b.end_date()];
+
+    // Comparing the raw dates would only tell us that the years are different.
+    // We compare how far each budget month starts (or ends) from its calendar months instead.
+    let start_shift = match start_dates {
+        [Some(a_start), Some(b_start)] => Some(
+            start_offset(b_start, second, period.first_month())
+                - start_offset(a_start, first, period.first_month()),
+        ),
+        _ => None,
+    };
+    let end_shift = match end_dates {
+        [Some(a_end), Some(b_end)] => Some(
+            end_offset(b_end, second, period.last_month())
+                - end_offset(a_end, first, period.last_month()),
+        ),
+        _ => None,
+    };
+
+    MonthComparison {
+        name: a.name.clone(),
+        weeks: [a.weeks.len(), b.weeks.len()],
+        week_difference: b.weeks.len() as i64 - a.weeks.len() as i64,
+        start_dates,
+        start_shift,
+        end_dates,
+        end_shift,
+        days: [a.days(), b.days()],
+        day_difference: b.days() - a.days(),
+    }
+}
+
+pub(crate) fn start_offset(date: NaiveDate, year: i32, month: ChronoMonth) -> i64 {
+    (date - first_day_month(year, month)).num_days()
+}
+
+pub(crate) fn end_offset(date: NaiveDate, year: i32, month: ChronoMonth) -> i64 {
+    (date - last_day_month(year, month)).num_days()
+}
+
+fn format_date(date: Option<NaiveDate>) -> String {
+    match date {
+        Some(date) => date.to_string(),
+        None => String::from("-"),
+    }
+}
+
+fn format_shift(shift: Option<i64>) -> String {
+    match shift {
+        Some(shift) => format!("({:+})", shift),
+        None => String::from("(-)"),
+    }
+}
+
+fn write_row(table: &mut String, name_width: usize, cells: [String; 5]) {
+    let [month, weeks, start, end, days] = cells;
+    writeln!(
+        table,
+        "{:<name_width$} {:<13} {:<31} {:<31} {}",
+        month, weeks, start, end, days
+    )
+    .unwrap();
+}
+
+impl YearComparison {
+    pub(crate) fn to_table(&self) -> String {
+        let [first, second] = self.years;
+        let mut table = String::new();
+        let name_width: usize = self
+            .months
+            .iter()
+            .map(|month| month.name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(10);
+
+        writeln!(table, "Comparing {} -> {}", first, second).unwrap();
+        write_row(
+            &mut table,
+            name_width,
+            ["Month", "Weeks", "Start", "End", "Days"].map(String::from),
+        );
+
+        for month in &self.months {
+            let cells = [
+                month.name.clone(),
+                format!(
+                    "{} -> {} ({:+})",
+                    month.weeks[0], month.weeks[1], month.week_difference
+                ),
+                format!(
+                    "{} -> {} {}",
+                    format_date(month.start_dates[0]),
+                    format_date(month.start_dates[1]),
+                    format_shift(month.start_shift)
+                ),
+                format!(
+                    "{} -> {} {}",
+                    format_date(month.end_dates[0]),
+                    format_date(month.end_dates[1]),
+                    format_shift(month.end_shift)
+                ),
+                format!(
+                    "{} -> {} ({:+})",
+                    month.days[0], month.days[1], month.day_difference
+                ),
+            ];
+            write_row(&mut table, name_width, cells);
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::CalendarBuilder;
+
+    #[test]
+    fn compare_same_year() {
+        let comparison = compare_years(2024, 2024, &Calendar::default()).unwrap();
+
+        assert_eq!(comparison.months.len(), 12);
+        for month in comparison.months {
+            assert_eq!(month.week_difference, 0);
+            assert_eq!(month.start_shift, Some(0));
+            assert_eq!(month.end_shift, Some(0));
+            assert_eq!(month.day_difference, 0);
+        }
+    }
+
+    #[test]
+    fn compare_2024_2025_january() {
+        let comparison = compare_years(2024, 2025, &Calendar::default()).unwrap();
+        let january = &comparison.months[0];
+
+        // 2024: Jan 01 to Jan 28, 2025: Jan 01 to Feb 02.
+        assert_eq!(january.name, "January");
+        assert_eq!(january.weeks, [4, 5]);
+        assert_eq!(january.week_difference, 1);
+        assert_eq!(january.start_shift, Some(0));
+        assert_eq!(
+            january.end_dates,
+            [
+                NaiveDate::from_ymd_opt(2024, 1, 28),
+                NaiveDate::from_ymd_opt(2025, 2, 2)
+            ]
+        );
+        assert_eq!(january.end_shift, Some(5));
+        assert_eq!(january.days, [28, 33]);
+        assert_eq!(january.day_difference, 5);
+    }
+
+    #[test]
+    fn compare_2024_2025_february() {
+        let comparison = compare_years(2024, 2025, &Calendar::default()).unwrap();
+        let february = &comparison.months[1];
+
+        // 2024: Jan 29 to Mar 03, 2025: Feb 03 to Mar 02.
+        assert_eq!(february.weeks, [5, 4]);
+        assert_eq!(february.week_difference, -1);
+        assert_eq!(february.start_shift, Some(5));
+        assert_eq!(february.end_shift, Some(-1));
+        assert_eq!(february.day_difference, -7);
+    }
+
+    #[test]
+    fn compare_table_has_a_row_per_month() {
+        let table = compare_years(2023, 2024, &Calendar::default())
+            .unwrap()
+            .to_table();
+
+        assert_eq!(table.lines().count(), 14);
+        assert!(table.contains("January    5 -> 4 (-1)"));
+    }
+
+    #[test]
+    fn compare_custom_periods() {
+        let calendar = CalendarBuilder::new()
+            .periods(vec![
+                Period::new("Winter", vec![ChronoMonth::January, ChronoMonth::February]),
+                Period::new("Spring", vec![ChronoMonth::March, ChronoMonth::April]),
+            ])
+            .build()
+            .unwrap();
+
+        let comparison = compare_years(2024, 2025, &calendar).unwrap();
+        let winter = &comparison.months[0];
+
+        // 2024: Jan 01 to Mar 03, 2025: Jan 01 to Mar 02.
+        assert_eq!(comparison.months.len(), 2);
+        assert_eq!(winter.name, "Winter");
+        assert_eq!(winter.weeks, [9, 9]);
+        assert_eq!(winter.start_shift, Some(0));
+        assert_eq!(winter.end_shift, Some(-1));
+    }
+}
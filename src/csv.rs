@@ -0,0 +1,300 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use chrono::{Datelike, IsoWeek};
+
+use crate::plan::Envelope;
+use crate::render::Renderer;
+
+const HEADER: [&str; 8] = [
+    "week_id",
+    "month",
+    "week",
+    "start_date",
+    "end_date",
+    "start_day",
+    "days",
+    "iso_week",
+];
+
+/// One row per budget week, for spreadsheets.
+///
+/// The columns are the id of the week, the name of its budget month, its position in the
+/// month (from 1), its first and last days, the weekday it starts on, how many days it lasts,
+/// and the ISO week of its first day (`2024-W05`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CsvRenderer {
+    /// The character between the fields.
+    ///
+    /// It can't be a double quote or a line break: the rows would be unreadable, rendering
+    /// fails with [`io::ErrorKind::InvalidInput`] instead.
+    pub delimiter: char,
+    /// Writes the names of the columns first.
+    pub header: bool,
+    pub quoting: Quoting,
+}
+
+impl Default for CsvRenderer {
+    fn default() -> Self {
+        CsvRenderer {
+            delimiter: ',',
+            header: true,
+            quoting: Quoting::default(),
+        }
+    }
+}
+
+/// Which fields of a CSV row are written between double quotes.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Quoting {
+    /// The fields holding the delimiter, a double quote or a line break.
+    #[default]
+    Necessary,
+    Always,
+    /// No field, even when it breaks the row.
+    Never,
+}
+
+impl fmt::Display for Quoting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Quoting::Necessary => write!(f, "necessary"),
+            Quoting::Always => write!(f, "always"),
+            Quoting::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl FromStr for Quoting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "necessary" => Ok(Quoting::Necessary),
+            "always" => Ok(Quoting::Always),
+            "never" => Ok(Quoting::Never),
+            _ => Err(format!(
+                "unknown quoting {}, expected necessary, always or never",
+                s
+            )),
+        }
+    }
+}
+
+/// Parses the `--delimiter` option, rejecting the characters [`CsvRenderer::delimiter`] can't
+/// be.
+#[cfg(feature = "cli")]
+pub(crate) fn parse_delimiter(delimiter: &str) -> Result<char, String> {
+    let mut chars = delimiter.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if valid_delimiter(c) => Ok(c),
+        (Some(_), None) => Err(format!(
+            "invalid delimiter {:?}, expected a character other than a double quote or a line break",
+            delimiter
+        )),
+        _ => Err(format!(
+            "invalid delimiter {:?}, expected a single character",
+            delimiter
+        )),
+    }
+}
+
+fn valid_delimiter(delimiter: char) -> bool {
+    !matches!(delimiter, '"' | '\n' | '\r')
+}
+
+impl CsvRenderer {
+    fn write_row<S: AsRef<str>>(
+        &self,
+        out: &mut dyn Write,
+        fields: impl IntoIterator<Item = S>,
+    ) -> io::Result<()> {
+        for (index, field) in fields.into_iter().enumerate() {
+            if index > 0 {
+                write!(out, "{}", self.delimiter)?;
+            }
+            self.write_field(out, field.as_ref())?;
+        }
+
+        writeln!(out)
+    }
+
+    fn write_field(&self, out: &mut dyn Write, field: &str) -> io::Result<()> {
+        let quoted: bool = match self.quoting {
+            Quoting::Necessary => field
+                .chars()
+                .any(|c| c == self.delimiter || matches!(c, '"' | '\n' | '\r')),
+            Quoting::Always => true,
+            Quoting::Never => false,
+        };
+
+        match quoted {
+            true => write!(out, "\"{}\"", field.replace('"', "\"\"")),
+            false => write!(out, "{}", field),
+        }
+    }
+}
+
+impl Renderer for CsvRenderer {
+    fn render(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+        if !valid_delimiter(self.delimiter) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid delimiter {:?}, expected a character other than a double quote or a line break",
+                    self.delimiter
+                ),
+            ));
+        }
+
+        if self.header {
+            self.write_row(out, HEADER)?;
+        }
+
+        for (id, month) in plan.month_ids().into_iter().zip(&plan.months) {
+            for (position, week) in (1..).zip(month.weeks()) {
+                let iso_week: IsoWeek = week.start_date().iso_week();
+
+                self.write_row(
+                    out,
+                    [
+                        id.week(position).to_string(),
+                        month.name().to_string(),
+                        position.to_string(),
+                        week.start_date().to_string(),
+                        week.end_date().to_string(),
+                        week.start_day().to_string(),
+                        week.days().to_string(),
+                        format!("{:04}-W{:02}", iso_week.year(), iso_week.week()),
+                    ],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::Calendar;
+    use crate::month::Month;
+
+    fn render(renderer: CsvRenderer, months: Vec<Month>) -> String {
+        let plan = Envelope::new(vec![2024], &Calendar::default(), months);
+        let mut out: Vec<u8> = Vec::new();
+        renderer.render(&plan, &mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    fn months_2024() -> Vec<Month> {
+        Calendar::default().year(2024).unwrap().into_months()
+    }
+
+    #[test]
+    fn one_row_per_week() {
+        let csv = render(CsvRenderer::default(), months_2024());
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "week_id,month,week,start_date,end_date,start_day,days,iso_week"
+        );
+        assert_eq!(
+            lines[1],
+            "2024-M01-W1,January,1,2024-01-01,2024-01-07,Mon,7,2024-W01"
+        );
+        assert_eq!(
+            lines[5],
+            "2024-M02-W1,February,1,2024-01-29,2024-02-04,Mon,7,2024-W05"
+        );
+        assert_eq!(
+            lines[53],
+            "2024-M12-W5,December,5,2024-12-30,2024-12-31,Mon,2,2025-W01"
+        );
+        assert_eq!(lines.len(), 54);
+    }
+
+    #[test]
+    fn delimiter_and_header() {
+        let renderer = CsvRenderer {
+            delimiter: ';',
+            header: false,
+            ..CsvRenderer::default()
+        };
+
+        let csv = render(renderer, months_2024());
+
+        assert!(csv.starts_with("2024-M01-W1;January;1;2024-01-01;2024-01-07;Mon;7;2024-W01\n"));
+    }
+
+    #[test]
+    fn invalid_delimiters() {
+        let plan = Envelope::new(vec![2024], &Calendar::default(), months_2024());
+
+        for delimiter in ['"', '\n', '\r'] {
+            let renderer = CsvRenderer {
+                delimiter,
+                ..CsvRenderer::default()
+            };
+            let mut out: Vec<u8> = Vec::new();
+
+            let error = renderer.render(&plan, &mut out).unwrap_err();
+
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+            assert!(out.is_empty());
+        }
+    }
+
+    #[test]
+    fn quote_fields() {
+        let weeks = months_2024()[0].weeks()[..1].to_vec();
+        let months = vec![Month::new("Rent, \"flat\"", weeks)];
+
+        let necessary = render(CsvRenderer::default(), months.clone());
+        let always = render(
+            CsvRenderer {
+                quoting: Quoting::Always,
+                header: false,
+                ..CsvRenderer::default()
+            },
+            months.clone(),
+        );
+        let never = render(
+            CsvRenderer {
+                quoting: Quoting::Never,
+                header: false,
+                ..CsvRenderer::default()
+            },
+            months,
+        );
+
+        assert!(necessary.ends_with(
+            "\n2024-M01-W1,\"Rent, \"\"flat\"\"\",1,2024-01-01,2024-01-07,Mon,7,2024-W01\n"
+        ));
+        assert!(always.starts_with("\"2024-M01-W1\",\"Rent, \"\"flat\"\"\",\"1\","));
+        assert!(never.starts_with("2024-M01-W1,Rent, \"flat\",1,"));
+    }
+
+    #[test]
+    fn parse_quoting() {
+        for quoting in [Quoting::Necessary, Quoting::Always, Quoting::Never] {
+            assert_eq!(quoting.to_string().parse(), Ok(quoting));
+        }
+        assert!("sometimes".parse::<Quoting>().is_err());
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn parse_delimiters() {
+        assert_eq!(parse_delimiter(";"), Ok(';'));
+        assert_eq!(parse_delimiter("\t"), Ok('\t'));
+        for delimiter in ["\"", "\n", "\r", "", ";;"] {
+            assert!(parse_delimiter(delimiter).is_err(), "{:?}", delimiter);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::error::Error;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use crate::calendar::Calendar;
+use crate::id::{MonthId, WeekId};
+use crate::month::Month;
+use crate::week::Week;
+
+/// Where "today" is.
+///
+/// The timezone comes from `--tz`, or the `timezone` setting of the config file.
+/// Without any, we fall back to the timezone of the machine.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Clock {
+    timezone: Option<Tz>,
+}
+
+impl Clock {
+    pub(crate) fn new(timezone: Option<Tz>) -> Clock {
+        Clock { timezone }
+    }
+
+    fn date_at(&self, now: DateTime<Utc>) -> NaiveDate {
+        match self.timezone {
+            Some(timezone) => now.with_timezone(&timezone).date_naive(),
+            None => now.with_timezone(&Local).date_naive(),
+        }
+    }
+
+    /// Resolves a date given by the user: `today`, `yesterday`, `tomorrow`, a date formatted
+    /// as `YYYY-MM-DD`, or the id of a budget week or month of `calendar`, for its first day.
+    pub(crate) fn resolve(
+        &self,
+        input: &str,
+        calendar: &Calendar,
+    ) -> Result<NaiveDate, Box<dyn Error>> {
+        if let Ok(id) = input.parse::<WeekId>() {
+            return calendar
+                .year(id.month.year)?
+                .week(id)
+                .map(Week::start_date)
+                .ok_or_else(|| format!("no budget week {}", id).into());
+        }
+        if let Ok(id) = input.parse::<MonthId>() {
+            return calendar
+                .year(id.year)?
+                .month(id)
+                .and_then(Month::start_date)
+                .ok_or_else(|| format!("no budget month {}", id).into());
+        }
+
+        self.resolve_at(input, Utc::now())
+    }
+
+    fn resolve_at(&self, input: &str, now: DateTime<Utc>) -> Result<NaiveDate, Box<dyn Error>> {
+        let today: NaiveDate = self.date_at(now);
+
+        match input.trim().to_lowercase().as_str() {
+            "today" => Ok(today),
+            "yesterday" => today
+                .pred_opt()
+                .ok_or_else(|| "yesterday is out of range".into()),
+            "tomorrow" => today
+                .succ_opt()
+                .ok_or_else(|| "tomorrow is out of range".into()),
+            date => NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                format!(
+                    "invalid date {}, expected today, yesterday, tomorrow, YYYY-MM-DD or an id like 2024-M02-W3",
+                    input
+                )
+                .into()
+            }),
+        }
+    }
+}
+
+pub(crate) fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.parse::<Tz>().map_err(|_| {
+        format!(
+            "unknown timezone {}, expected a name like Europe/Paris",
+            name
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use chrono::TimeZone;
+
+    // 2024-03-31 23:30 in UTC, it is already April 1st in Paris but still March 31st in New York.
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 31, 23, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn today_in_paris() {
+        let clock = Clock::new(Some(chrono_tz::Europe::Paris));
+
+        assert_eq!(
+            clock.date_at(now()),
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn today_in_new_york() {
+        let clock = Clock::new(Some(chrono_tz::America::New_York));
+
+        assert_eq!(
+            clock.date_at(now()),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_relative_dates() {
+        let clock = Clock::new(Some(chrono_tz::Europe::Paris));
+
+        assert_eq!(
+            clock.resolve_at("today", now()).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()
+        );
+        assert_eq!(
+            clock.resolve_at("Yesterday", now()).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 31).unwrap()
+        );
+        assert_eq!(
+            clock.resolve_at("tomorrow", now()).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_absolute_date() {
+        let clock = Clock::new(None);
+
+        assert_eq!(
+            clock.resolve_at("2023-05-22", now()).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 5, 22).unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_ids() {
+        let clock = Clock::new(None);
+        let calendar = Calendar::default();
+
+        assert_eq!(
+            clock.resolve("2024-M02-W3", &calendar).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 12).unwrap()
+        );
+        assert_eq!(
+            clock.resolve("2024-M02", &calendar).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 29).unwrap()
+        );
+        assert_eq!(
+            clock
+                .resolve("2024-M02-W6", &calendar)
+                .unwrap_err()
+                .to_string(),
+            "no budget week 2024-M02-W6"
+        );
+        assert_eq!(
+            clock
+                .resolve("2024-M13", &calendar)
+                .unwrap_err()
+                .to_string(),
+            "no budget month 2024-M13"
+        );
+    }
+
+    #[test]
+    fn resolve_invalid_date() {
+        let clock = Clock::new(None);
+
+        assert!(clock.resolve_at("2023-02-30", now()).is_err());
+        assert!(clock.resolve_at("next week", now()).is_err());
+    }
+
+    #[test]
+    fn parse_known_timezone() {
+        assert_eq!(parse_timezone("Europe/Paris"), Ok(chrono_tz::Europe::Paris));
+    }
+
+    #[test]
+    fn parse_unknown_timezone() {
+        assert!(parse_timezone("Europe/Atlantis").is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::year::supported_years;
+
+/// Everything that can go wrong when building a budget calendar.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CalendarError {
+    /// The year is outside of the range supported by chrono.
+    YearOutOfRange(i32),
+    /// The budget periods don't follow the rules of [`crate::Periods::new`].
+    #[cfg(feature = "alloc")]
+    InvalidPeriods(String),
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::YearOutOfRange(year) => write!(
+                f,
+                "the year {} is out of the supported range ({} to {})",
+                year,
+                supported_years().start(),
+                supported_years().end()
+            ),
+            #[cfg(feature = "alloc")]
+            CalendarError::InvalidPeriods(reason) => {
+                write!(f, "invalid budget periods: {}", reason)
+            }
+        }
+    }
+}
+
+impl core::error::Error for CalendarError {}
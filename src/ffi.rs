@@ -0,0 +1,362 @@
+//! The C API, built with `--features ffi`. Its header is `include/budget_planner.h`.
+//!
+//! A [`BpCalendar`] is one year split into budget months. Months and weeks are addressed by
+//! their index, from 0. Every function taking a pointer accepts `NULL` and reports it.
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::CalendarBuilder;
+use crate::week::Week;
+use crate::year::BudgetYear;
+
+/// What a function of the C API returns.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BpStatus {
+    Ok = 0,
+    /// A pointer given to the function is `NULL`.
+    NullPointer,
+    /// The options are not valid UTF-8 or JSON, or break the rules of the budget periods.
+    InvalidOptions,
+    /// The year is out of the range supported by chrono.
+    YearOutOfRange,
+    /// The month or week index is past the last one.
+    IndexOutOfRange,
+    /// The date doesn't exist, or isn't part of any budget month of the year.
+    NotFound,
+}
+
+/// A calendar date.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BpDate {
+    pub year: i32,
+    /// From 1 to 12.
+    pub month: u32,
+    /// From 1 to 31.
+    pub day: u32,
+}
+
+/// A budget week, both days included.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BpWeek {
+    pub start: BpDate,
+    pub end: BpDate,
+}
+
+/// A year split into budget months, created by [`bp_calendar_new`] and freed by [`bp_calendar_free`].
+pub struct BpCalendar {
+    year: BudgetYear,
+    // The names of the months, kept alive for `bp_calendar_month_name`.
+    names: Vec<CString>,
+}
+
+impl From<NaiveDate> for BpDate {
+    fn from(date: NaiveDate) -> Self {
+        BpDate {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+}
+
+impl From<&Week> for BpWeek {
+    fn from(week: &Week) -> Self {
+        BpWeek {
+            start: week.start_date.into(),
+            end: week.end_date.into(),
+        }
+    }
+}
+
+/// Splits `year` and stores the calendar in `out`.
+///
+/// `options` is `NULL` or a JSON object with the options of the config file (but `timezone`),
+/// like `{"week_start": "Sun"}`.
+///
+/// # Safety
+///
+/// `options` must be `NULL` or a valid C string, `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn bp_calendar_new(
+    year: i32,
+    options: *const c_char,
+    out: *mut *mut BpCalendar,
+) -> BpStatus {
+    if out.is_null() {
+        return BpStatus::NullPointer;
+    }
+    *out = ptr::null_mut();
+
+    let builder: CalendarBuilder = if options.is_null() {
+        CalendarBuilder::new()
+    } else {
+        let parsed = CStr::from_ptr(options)
+            .to_str()
+            .ok()
+            .and_then(|options| serde_json::from_str(options).ok());
+
+        match parsed {
+            Some(builder) => builder,
+            None => return BpStatus::InvalidOptions,
+        }
+    };
+
+    let year: BudgetYear = match builder.build() {
+        Ok(calendar) => match calendar.year(year) {
+            Ok(year) => year,
+            Err(_) => return BpStatus::YearOutOfRange,
+        },
+        Err(_) => return BpStatus::InvalidOptions,
+    };
+
+    // Names come from Rust strings and TOML or JSON, they can't hold a NUL.
+    let names: Vec<CString> = year
+        .months()
+        .iter()
+        .map(|month| CString::new(month.name()).unwrap_or_default())
+        .collect();
+
+    *out = Box::into_raw(Box::new(BpCalendar { year, names }));
+
+    BpStatus::Ok
+}
+
+/// Frees a calendar created by [`bp_calendar_new`], `NULL` is ignored.
+///
+/// # Safety
+///
+/// `calendar` must come from [`bp_calendar_new`] and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn bp_calendar_free(calendar: *mut BpCalendar) {
+    if !calendar.is_null() {
+        drop(Box::from_raw(calendar));
+    }
+}
+
+/// The number of budget months, 0 for `NULL`.
+///
+/// # Safety
+///
+/// `calendar` must be `NULL` or come from [`bp_calendar_new`].
+#[no_mangle]
+pub unsafe extern "C" fn bp_calendar_month_count(calendar: *const BpCalendar) -> usize {
+    calendar
+        .as_ref()
+        .map_or(0, |calendar| calendar.year.months().len())
+}
+
+/// The name of a budget month, `NULL` when out of range. It lives as long as the calendar.
+///
+/// # Safety
+///
+/// `calendar` must be `NULL` or come from [`bp_calendar_new`].
+#[no_mangle]
+pub unsafe extern "C" fn bp_calendar_month_name(
+    calendar: *const BpCalendar,
+    month: usize,
+) -> *const c_char {
+    calendar
+        .as_ref()
+        .and_then(|calendar| calendar.names.get(month))
+        .map_or(ptr::null(), |name| name.as_ptr())
+}
+
+/// The number of weeks of a budget month, 0 when out of range.
+///
+/// # Safety
+///
+/// `calendar` must be `NULL` or come from [`bp_calendar_new`].
+#[no_mangle]
+pub unsafe extern "C" fn bp_calendar_week_count(
+    calendar: *const BpCalendar,
+    month: usize,
+) -> usize {
+    calendar
+        .as_ref()
+        .and_then(|calendar| calendar.year.months().get(month))
+        .map_or(0, |month| month.weeks().len())
+}
+
+/// Stores the first and last days of a week of a budget month in `out`.
+///
+/// # Safety
+///
+/// `calendar` must be `NULL` or come from [`bp_calendar_new`], `out` must be `NULL` or valid.
+#[no_mangle]
+pub unsafe extern "C" fn bp_calendar_week(
+    calendar: *const BpCalendar,
+    month: usize,
+    week: usize,
+    out: *mut BpWeek,
+) -> BpStatus {
+    let (Some(calendar), Some(out)) = (calendar.as_ref(), out.as_mut()) else {
+        return BpStatus::NullPointer;
+    };
+
+    match calendar
+        .year
+        .months()
+        .get(month)
+        .and_then(|month| month.weeks().get(week))
+    {
+        Some(week) => {
+            *out = week.into();
+            BpStatus::Ok
+        }
+        None => BpStatus::IndexOutOfRange,
+    }
+}
+
+/// Stores the indexes of the budget month and of the week (in that month) of `date`.
+///
+/// # Safety
+///
+/// `calendar` must be `NULL` or come from [`bp_calendar_new`], `month` and `week`
+/// must be `NULL` or valid.
+#[no_mangle]
+pub unsafe extern "C" fn bp_calendar_lookup(
+    calendar: *const BpCalendar,
+    date: BpDate,
+    month: *mut usize,
+    week: *mut usize,
+) -> BpStatus {
+    let (Some(calendar), Some(month), Some(week)) =
+        (calendar.as_ref(), month.as_mut(), week.as_mut())
+    else {
+        return BpStatus::NullPointer;
+    };
+
+    let Some(date) = NaiveDate::from_ymd_opt(date.year, date.month, date.day) else {
+        return BpStatus::NotFound;
+    };
+
+    for (month_index, budget_month) in calendar.year.months().iter().enumerate() {
+        if let Some(week_index) = budget_month
+            .weeks()
+            .iter()
+            .position(|budget_week| budget_week.contains(date))
+        {
+            *month = month_index;
+            *week = week_index;
+            return BpStatus::Ok;
+        }
+    }
+
+    BpStatus::NotFound
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn calendar(year: i32, options: Option<&CStr>) -> *mut BpCalendar {
+        let mut calendar: *mut BpCalendar = ptr::null_mut();
+        let options = options.map_or(ptr::null(), CStr::as_ptr);
+
+        assert_eq!(
+            unsafe { bp_calendar_new(year, options, &mut calendar) },
+            BpStatus::Ok
+        );
+
+        calendar
+    }
+
+    #[test]
+    fn months_and_weeks() {
+        let calendar = calendar(2024, None);
+
+        unsafe {
+            assert_eq!(bp_calendar_month_count(calendar), 12);
+            assert_eq!(
+                CStr::from_ptr(bp_calendar_month_name(calendar, 1)),
+                c"February"
+            );
+            assert_eq!(bp_calendar_week_count(calendar, 1), 5);
+
+            let mut week = BpWeek {
+                start: BpDate {
+                    year: 0,
+                    month: 0,
+                    day: 0,
+                },
+                end: BpDate {
+                    year: 0,
+                    month: 0,
+                    day: 0,
+                },
+            };
+            assert_eq!(bp_calendar_week(calendar, 1, 0, &mut week), BpStatus::Ok);
+            assert_eq!(
+                week.start,
+                BpDate {
+                    year: 2024,
+                    month: 1,
+                    day: 29
+                }
+            );
+            assert_eq!(
+                bp_calendar_week(calendar, 12, 0, &mut week),
+                BpStatus::IndexOutOfRange
+            );
+
+            bp_calendar_free(calendar);
+        }
+    }
+
+    #[test]
+    fn lookup_a_date() {
+        let calendar = calendar(2024, Some(c"{\"week_start\": \"Sun\"}"));
+        let (mut month, mut week) = (0, 0);
+        let date = BpDate {
+            year: 2024,
+            month: 1,
+            day: 7,
+        };
+
+        unsafe {
+            assert_eq!(
+                bp_calendar_lookup(calendar, date, &mut month, &mut week),
+                BpStatus::Ok
+            );
+            assert_eq!((month, week), (0, 1));
+
+            let other_year = BpDate { year: 2025, ..date };
+            assert_eq!(
+                bp_calendar_lookup(calendar, other_year, &mut month, &mut week),
+                BpStatus::NotFound
+            );
+
+            bp_calendar_free(calendar);
+        }
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let mut calendar: *mut BpCalendar = ptr::null_mut();
+
+        unsafe {
+            assert_eq!(
+                bp_calendar_new(2024, c"{\"periods\": []}".as_ptr(), &mut calendar),
+                BpStatus::InvalidOptions
+            );
+            assert!(calendar.is_null());
+            assert_eq!(
+                bp_calendar_new(i32::MAX, ptr::null(), &mut calendar),
+                BpStatus::YearOutOfRange
+            );
+            assert_eq!(
+                bp_calendar_new(2024, ptr::null(), ptr::null_mut()),
+                BpStatus::NullPointer
+            );
+            assert_eq!(bp_calendar_month_count(ptr::null()), 0);
+            assert!(bp_calendar_month_name(ptr::null(), 0).is_null());
+            bp_calendar_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+use crate::id::{MonthId, WeekId};
+use crate::month::Month;
+use crate::plan::Envelope;
+use crate::render::Renderer;
+use crate::week::Week;
+
+/// Lines longer than this many bytes are folded, see RFC 5545 3.1.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// The years of a DATE value, written with exactly 4 digits, see RFC 5545 3.3.4.
+const SUPPORTED_YEARS: RangeInclusive<i32> = 0..=9999;
+
+/// An iCalendar file (RFC 5545) with an all-day event per budget month, to share the budget
+/// months in a calendar application.
+///
+/// The UID of an event is the id of its month or week, like `2024-M02@budget-planner`, so
+/// importing the file again updates the events instead of duplicating them.
+///
+/// The dates have years of 4 digits: rendering a plan with a budget month outside of the
+/// years 0 to 9999, or ending on the last day of 9999, fails with
+/// [`io::ErrorKind::InvalidInput`].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct IcsRenderer {
+    /// Also adds an event per budget week.
+    pub weeks: bool,
+    /// When the events were written, in UTC.
+    ///
+    /// `None` keeps the output reproducible: every event is stamped with midnight of
+    /// January 1st of the first year of the plan.
+    pub stamp: Option<NaiveDateTime>,
+}
+
+impl Renderer for IcsRenderer {
+    fn render(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+        // Checked first to never write half a calendar.
+        for month in &plan.months {
+            if let (Some(start_date), Some(end_date)) = (month.start_date(), month.end_date()) {
+                check_year(start_date)?;
+                check_year(day_after(end_date))?;
+            }
+        }
+
+        let first_year: i32 = plan.years.first().copied().unwrap_or(1970);
+        let stamp: NaiveDateTime = self.stamp.unwrap_or_else(|| {
+            NaiveDate::from_ymd_opt(first_year, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        });
+        let mut calendar = Lines { out };
+
+        calendar.line("BEGIN:VCALENDAR")?;
+        calendar.line("VERSION:2.0")?;
+        calendar.line(&format!(
+            "PRODID:-//budget_planner//budget_planner {}//EN",
+            env!("CARGO_PKG_VERSION")
+        ))?;
+        calendar.line("CALSCALE:GREGORIAN")?;
+
+        for (id, month) in plan.month_ids().into_iter().zip(&plan.months) {
+            let (Some(start_date), Some(end_date)) = (month.start_date(), month.end_date()) else {
+                continue;
+            };
+
+            calendar.event(Event {
+                uid: id.to_string(),
+                stamp,
+                start_date,
+                end_date,
+                summary: month_summary(id, month),
+                description: month_description(id, month),
+            })?;
+
+            if self.weeks {
+                for (position, week) in (1..).zip(month.weeks()) {
+                    let week_id: WeekId = id.week(position);
+
+                    calendar.event(Event {
+                        uid: week_id.to_string(),
+                        stamp,
+                        start_date: week.start_date(),
+                        end_date: week.end_date(),
+                        summary: format!("{} week {}", month_summary(id, month), position),
+                        description: week_line(week_id, week),
+                    })?;
+                }
+            }
+        }
+
+        calendar.line("END:VCALENDAR")
+    }
+}
+
+/// An all-day event, from `start_date` to `end_date` included.
+struct Event {
+    uid: String,
+    stamp: NaiveDateTime,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    summary: String,
+    description: String,
+}
+
+/// Writes content lines, ending with CRLF and folded when too long.
+struct Lines<'a> {
+    out: &'a mut dyn Write,
+}
+
+impl Lines<'_> {
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        let mut rest: &str = line;
+        let mut length: usize = MAX_LINE_LENGTH;
+
+        while rest.len() > length {
+            // Never cut a character in two.
+            let cut: usize = (1..=length)
+                .rev()
+                .find(|index| rest.is_char_boundary(*index))
+                .unwrap();
+            write!(self.out, "{}\r\n ", &rest[..cut])?;
+            rest = &rest[cut..];
+            // The space starting the next lines counts.
+            length = MAX_LINE_LENGTH - 1;
+        }
+
+        write!(self.out, "{}\r\n", rest)
+    }
+
+    fn event(&mut self, event: Event) -> io::Result<()> {
+        self.line("BEGIN:VEVENT")?;
+        self.line(&format!("UID:{}@budget-planner", event.uid))?;
+        self.line(&format!("DTSTAMP:{}", event.stamp.format("%Y%m%dT%H%M%SZ")))?;
+        self.line(&format!("DTSTART;VALUE=DATE:{}", date(event.start_date)))?;
+        self.line(&format!(
+            "DTEND;VALUE=DATE:{}",
+            date(day_after(event.end_date))
+        ))?;
+        self.line(&format!("SUMMARY:{}", escape(&event.summary)))?;
+        self.line(&format!("DESCRIPTION:{}", escape(&event.description)))?;
+        // The budget months don't make anyone busy.
+        self.line("TRANSP:TRANSPARENT")?;
+        self.line("END:VEVENT")
+    }
+}
+
+/// DTEND of an all-day event is the day after the last one.
+fn day_after(date: NaiveDate) -> NaiveDate {
+    date.succ_opt().unwrap_or(date)
+}
+
+fn check_year(date: NaiveDate) -> io::Result<()> {
+    match SUPPORTED_YEARS.contains(&date.year()) {
+        true => Ok(()),
+        false => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "iCalendar dates go from year 0 to 9999, {} can't be written",
+                date
+            ),
+        )),
+    }
+}
+
+fn date(date: NaiveDate) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month(), date.day())
+}
+
+/// Escapes the special characters of a text value, see RFC 5545 3.3.11.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn month_summary(id: MonthId, month: &Month) -> String {
+    format!("{} {}", month.name(), id.year)
+}
+
+fn month_description(id: MonthId, month: &Month) -> String {
+    (1..)
+        .zip(month.weeks())
+        .map(|(position, week)| week_line(id.week(position), week))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn week_line(id: WeekId, week: &Week) -> String {
+    format!(
+        "{}: {} to {} ({} days)",
+        id,
+        week.start_date(),
+        week.end_date(),
+        week.days()
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::Calendar;
+
+    fn render_year(renderer: IcsRenderer, year: i32) -> io::Result<String> {
+        let calendar = Calendar::default();
+        let plan = Envelope::new(
+            vec![year],
+            &calendar,
+            calendar.year(year).unwrap().into_months(),
+        );
+        let mut out: Vec<u8> = Vec::new();
+        renderer.render(&plan, &mut out)?;
+
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    fn render(renderer: IcsRenderer) -> String {
+        render_year(renderer, 2024).unwrap()
+    }
+
+    #[test]
+    fn one_event_per_month() {
+        let ics = render(IcsRenderer::default());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 12);
+        assert!(ics.contains(concat!(
+            "BEGIN:VEVENT\r\n",
+            "UID:2024-M02@budget-planner\r\n",
+            "DTSTAMP:20240101T000000Z\r\n",
+            "DTSTART;VALUE=DATE:20240129\r\n",
+            "DTEND;VALUE=DATE:20240304\r\n",
+            "SUMMARY:February 2024\r\n",
+            "DESCRIPTION:2024-M02-W1: 2024-01-29 to 2024-02-04 (7 days)\\n2024-M02-W2: 20\r\n",
+            " 24-02-05 to 2024-02-11 (7 days)\\n2024-M02-W3: 2024-02-12 to 2024-02-18 (7 \r\n",
+        )));
+    }
+
+    #[test]
+    fn one_event_per_week() {
+        let stamp = NaiveDate::from_ymd_opt(2024, 5, 17)
+            .unwrap()
+            .and_hms_opt(8, 30, 0)
+            .unwrap();
+
+        let ics = render(IcsRenderer {
+            weeks: true,
+            stamp: Some(stamp),
+        });
+
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 12 + 53);
+        assert!(ics.contains(concat!(
+            "UID:2024-M12-W5@budget-planner\r\n",
+            "DTSTAMP:20240517T083000Z\r\n",
+            "DTSTART;VALUE=DATE:20241230\r\n",
+            "DTEND;VALUE=DATE:20250101\r\n",
+            "SUMMARY:December 2024 week 5\r\n",
+            "DESCRIPTION:2024-M12-W5: 2024-12-30 to 2024-12-31 (2 days)\r\n",
+        )));
+    }
+
+    #[test]
+    fn fold_long_lines() {
+        let ics = render(IcsRenderer::default());
+
+        for line in ics.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_LENGTH, "{}", line);
+        }
+    }
+
+    #[test]
+    fn fold_between_characters() {
+        let mut out: Vec<u8> = Vec::new();
+        let line: String = "é".repeat(50);
+
+        Lines { out: &mut out }.line(&line).unwrap();
+
+        let folded = String::from_utf8(out).unwrap();
+        assert_eq!(folded.replace("\r\n ", ""), format!("{}\r\n", line));
+        assert!(folded
+            .split("\r\n")
+            .all(|line| line.len() <= MAX_LINE_LENGTH));
+    }
+
+    #[test]
+    fn years_of_four_digits() {
+        assert!(render_year(IcsRenderer::default(), 0).is_ok());
+        for year in [-5, 9999, 12000] {
+            assert_eq!(
+                render_year(IcsRenderer::default(), year)
+                    .unwrap_err()
+                    .kind(),
+                io::ErrorKind::InvalidInput,
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn escape_text() {
+        assert_eq!(
+            escape("Rent; water, gas\\\nfood"),
+            "Rent\\; water\\, gas\\\\\\nfood"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use core::fmt;
+use core::str::FromStr;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use chrono::Datelike;
+
+use crate::month::Month;
+
+/// Identifies a budget month by its year and position: `2024-M02` is the second budget month
+/// of 2024, whatever its name.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct MonthId {
+    pub year: i32,
+    /// From 1.
+    pub month: u32,
+}
+
+/// Identifies a budget week by its month and position: `2024-M02-W3` is the third week
+/// of the second budget month of 2024.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct WeekId {
+    pub month: MonthId,
+    /// From 1.
+    pub week: u32,
+}
+
+impl MonthId {
+    pub fn new(year: i32, month: u32) -> MonthId {
+        MonthId { year, month }
+    }
+
+    /// The id of a week of the month, from 1.
+    pub fn week(&self, week: u32) -> WeekId {
+        WeekId { month: *self, week }
+    }
+}
+
+impl WeekId {
+    pub fn new(year: i32, month: u32, week: u32) -> WeekId {
+        MonthId::new(year, month).week(week)
+    }
+}
+
+impl fmt::Display for MonthId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Like chrono, years past 9999 or before 0 get a sign.
+        match self.year {
+            0..=9999 => write!(f, "{:04}-M{:02}", self.year, self.month),
+            _ => write!(f, "{:+05}-M{:02}", self.year, self.month),
+        }
+    }
+}
+
+impl fmt::Display for WeekId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-W{}", self.month, self.week)
+    }
+}
+
+impl FromStr for MonthId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid budget month {}, expected an id like 2024-M02", s);
+
+        let (year, month) = s.trim().rsplit_once("-M").ok_or_else(invalid)?;
+
+        Ok(MonthId {
+            year: parse_year(year).ok_or_else(invalid)?,
+            month: parse_position(month).ok_or_else(invalid)?,
+        })
+    }
+}
+
+impl FromStr for WeekId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid budget week {}, expected an id like 2024-M02-W3", s);
+
+        let (month, week) = s.trim().rsplit_once("-W").ok_or_else(invalid)?;
+
+        Ok(WeekId {
+            month: month.parse().map_err(|_| invalid())?,
+            week: parse_position(week).ok_or_else(invalid)?,
+        })
+    }
+}
+
+fn parse_year(year: &str) -> Option<i32> {
+    let digits: &str = year.strip_prefix(['+', '-']).unwrap_or(year);
+
+    if digits.len() < 4 || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    year.parse().ok()
+}
+
+/// A position from 1, written with digits only.
+fn parse_position(position: &str) -> Option<u32> {
+    if position.is_empty() || !position.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    position.parse().ok().filter(|position| *position > 0)
+}
+
+/// The id of each budget month of a plan, counting from 1 again at every calendar year.
+///
+/// The year of a month is the one of its first week, a month without week stays in the
+/// year of the previous month (or `first_year` for the first ones).
+pub(crate) fn month_ids(months: &[Month], first_year: i32) -> Vec<MonthId> {
+    let mut ids: Vec<MonthId> = Vec::with_capacity(months.len());
+    let mut previous = MonthId::new(first_year, 0);
+
+    for month in months {
+        let year: i32 = month
+            .start_date()
+            .map_or(previous.year, |start_date| start_date.year());
+        let id = match year == previous.year {
+            true => MonthId::new(year, previous.month + 1),
+            false => MonthId::new(year, 1),
+        };
+
+        ids.push(id);
+        previous = id;
+    }
+
+    ids
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+    use crate::week::Week;
+
+    impl Serialize for MonthId {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl Serialize for WeekId {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MonthId {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            String::deserialize(deserializer)?
+                .parse()
+                .map_err(serde::de::Error::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for WeekId {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            String::deserialize(deserializer)?
+                .parse()
+                .map_err(serde::de::Error::custom)
+        }
+    }
+
+    #[derive(Serialize)]
+    struct IdentifiedWeek<'a> {
+        id: WeekId,
+        #[serde(flatten)]
+        week: &'a Week,
+    }
+
+    /// A budget month written with its id and the ids of its weeks.
+    #[derive(Serialize)]
+    pub(crate) struct IdentifiedMonth<'a> {
+        id: MonthId,
+        name: &'a str,
+        weeks: Vec<IdentifiedWeek<'a>>,
+    }
+
+    impl<'a> IdentifiedMonth<'a> {
+        pub(crate) fn new(id: MonthId, month: &'a Month) -> IdentifiedMonth<'a> {
+            IdentifiedMonth {
+                id,
+                name: month.name(),
+                weeks: (1..)
+                    .zip(month.weeks())
+                    .map(|(position, week)| IdentifiedWeek {
+                        id: id.week(position),
+                        week,
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    /// Budget months written with the ids of the months and of their weeks.
+    pub(crate) fn serialize_months<S: Serializer>(
+        months: &[Month],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let first_year: i32 = months
+            .iter()
+            .find_map(Month::start_date)
+            .map_or(0, |start_date| start_date.year());
+
+        serializer.collect_seq(
+            month_ids(months, first_year)
+                .into_iter()
+                .zip(months)
+                .map(|(id, month)| IdentifiedMonth::new(id, month)),
+        )
+    }
+
+    /// The months of a plan with their ids, see [`serialize_months`].
+    #[cfg(any(feature = "json", feature = "wasm"))]
+    pub(crate) struct IdentifiedMonths<'a>(pub(crate) &'a [Month]);
+
+    #[cfg(any(feature = "json", feature = "wasm"))]
+    impl Serialize for IdentifiedMonths<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_months(self.0, serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use serialize::serialize_months;
+#[cfg(feature = "wasm")]
+pub(crate) use serialize::IdentifiedMonth;
+#[cfg(any(feature = "json", feature = "wasm"))]
+pub(crate) use serialize::IdentifiedMonths;
+
+/// Documents the `id` written with a budget month.
+#[cfg(feature = "schema")]
+pub(crate) fn month_id_schema(schema: &mut schemars::Schema) {
+    id_schema(
+        schema,
+        "The year and position of the budget month, like 2024-M02.",
+        "^([0-9]{4}|[+-][0-9]{4,})-M[0-9]{2,}$",
+    );
+}
+
+/// Documents the `id` written with a budget week.
+#[cfg(feature = "schema")]
+pub(crate) fn week_id_schema(schema: &mut schemars::Schema) {
+    id_schema(
+        schema,
+        "The budget month and position of the week, like 2024-M02-W3.",
+        "^([0-9]{4}|[+-][0-9]{4,})-M[0-9]{2,}-W[0-9]+$",
+    );
+}
+
+#[cfg(feature = "schema")]
+fn id_schema(schema: &mut schemars::Schema, description: &str, pattern: &str) {
+    let id = schemars::json_schema!({
+        "description": description,
+        "type": "string",
+        "pattern": pattern
+    });
+
+    if let Some(properties) = schema
+        .get_mut("properties")
+        .and_then(|properties| properties.as_object_mut())
+    {
+        properties.insert("id".into(), id.to_value());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn display_ids() {
+        assert_eq!(MonthId::new(2024, 2).to_string(), "2024-M02");
+        assert_eq!(WeekId::new(2024, 2, 3).to_string(), "2024-M02-W3");
+        assert_eq!(MonthId::new(12, 12).to_string(), "0012-M12");
+        assert_eq!(MonthId::new(-1, 1).to_string(), "-0001-M01");
+        assert_eq!(MonthId::new(10000, 1).to_string(), "+10000-M01");
+    }
+
+    #[test]
+    fn parse_ids() {
+        assert_eq!("2024-M02".parse(), Ok(MonthId::new(2024, 2)));
+        assert_eq!("2024-M02-W3".parse(), Ok(WeekId::new(2024, 2, 3)));
+        assert_eq!("-0001-M01-W1".parse(), Ok(WeekId::new(-1, 1, 1)));
+        assert_eq!("+10000-M12".parse(), Ok(MonthId::new(10000, 12)));
+
+        for id in ["2024-M02-W3", "24-M02", "2024-M00", "2024-02", "2024-M+2"] {
+            assert!(id.parse::<MonthId>().is_err(), "{}", id);
+        }
+        for id in [
+            "2024-M02",
+            "2024-M02-W0",
+            "2024-M02-W",
+            "2024-M02-Wx",
+            "2024-W3",
+        ] {
+            assert!(id.parse::<WeekId>().is_err(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for id in [
+            WeekId::new(2024, 12, 5),
+            WeekId::new(0, 1, 1),
+            WeekId::new(-262143, 3, 2),
+            WeekId::new(262142, 10, 4),
+        ] {
+            assert_eq!(id.to_string().parse(), Ok(id));
+            assert_eq!(id.month.to_string().parse(), Ok(id.month));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+
+use crate::calendar::Calendar;
+use crate::error::CalendarError;
+use crate::id::{MonthId, WeekId};
+use crate::week::Week;
+
+/// The budget week, and month, a date belongs to.
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct Lookup {
+    date: NaiveDate,
+    /// `None` when the week is left out of every budget period.
+    month: Option<String>,
+    week: Week,
+    /// `None` like `month`.
+    month_id: Option<MonthId>,
+    /// `None` like `month`.
+    week_id: Option<WeekId>,
+}
+
+pub(crate) fn lookup(date: NaiveDate, calendar: &Calendar) -> Result<Lookup, CalendarError> {
+    let year = calendar.year(date.year())?;
+
+    // The weeks of a year cover every one of its days.
+    let week: Week = *year.week_of(date).unwrap();
+    let month: Option<String> = year.month_of(date).map(|month| month.name.clone());
+    let week_id: Option<WeekId> = year.id_of(date);
+
+    Ok(Lookup {
+        date,
+        month,
+        week,
+        month_id: week_id.map(|id| id.month),
+        week_id,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Month::*;
+
+    use super::*;
+    use crate::calendar::CalendarBuilder;
+    use crate::periods::Period;
+
+    #[test]
+    fn lookup_week_owned_by_next_month() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 30).unwrap();
+
+        let result = lookup(date, &Calendar::default()).unwrap();
+
+        assert_eq!(result.month, Some(String::from("February")));
+        assert_eq!(result.month_id, Some(MonthId::new(2024, 2)));
+        assert_eq!(result.week_id, Some(WeekId::new(2024, 2, 1)));
+        assert_eq!(
+            result.week.start_date,
+            NaiveDate::from_ymd_opt(2024, 1, 29).unwrap()
+        );
+        assert_eq!(
+            result.week.end_date,
+            NaiveDate::from_ymd_opt(2024, 2, 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn lookup_first_day_of_year() {
+        let date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+
+        let result = lookup(date, &Calendar::default()).unwrap();
+
+        assert_eq!(result.month, Some(String::from("January")));
+        assert_eq!(result.week.start_date, date);
+        assert_eq!(result.week.end_date, date);
+    }
+
+    #[test]
+    fn lookup_week_outside_periods() {
+        let calendar = CalendarBuilder::new()
+            .periods(vec![Period::from(January)])
+            .build()
+            .unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 7, 14).unwrap();
+
+        let result = lookup(date, &calendar).unwrap();
+
+        assert_eq!(result.month, None);
+        assert_eq!(result.week_id, None);
+        assert_eq!(
+            result.week.start_date,
+            NaiveDate::from_ymd_opt(2024, 7, 8).unwrap()
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::io::{self, Write};
+
+use chrono::NaiveDate;
+
+use crate::id::MonthId;
+use crate::month::Month;
+use crate::plan::Envelope;
+use crate::render::Renderer;
+
+/// Markdown tables, to paste in a wiki page.
+///
+/// By default each budget month gets a heading and a table of its weeks.
+/// [`MarkdownRenderer::compact`] writes a single table instead, with a row per budget month.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct MarkdownRenderer {
+    /// A single table with one row per budget month, without their weeks.
+    pub compact: bool,
+}
+
+impl MarkdownRenderer {
+    fn render_compact(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "| Month | Id | Weeks | Start | End | Days |")?;
+        writeln!(out, "| --- | --- | ---: | --- | --- | ---: |")?;
+
+        for (id, month) in plan.month_ids().into_iter().zip(&plan.months) {
+            writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {} |",
+                title(id, month),
+                id,
+                month.weeks().len(),
+                date(month.start_date()),
+                date(month.end_date()),
+                month.days()
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn render_months(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+        for (index, (id, month)) in plan.month_ids().into_iter().zip(&plan.months).enumerate() {
+            if index > 0 {
+                writeln!(out)?;
+            }
+            writeln!(out, "## {}", title(id, month))?;
+            writeln!(out)?;
+            writeln!(out, "| Week | Start | End | Start day | Days |")?;
+            writeln!(out, "| --- | --- | --- | --- | ---: |")?;
+
+            for (position, week) in (1..).zip(month.weeks()) {
+                writeln!(
+                    out,
+                    "| {} | {} | {} | {} | {} |",
+                    id.week(position),
+                    week.start_date(),
+                    week.end_date(),
+                    week.start_day(),
+                    week.days()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+        match self.compact {
+            true => self.render_compact(plan, out),
+            false => self.render_months(plan, out),
+        }
+    }
+}
+
+/// The name and year of a budget month, escaped for a heading or a cell.
+fn title(id: MonthId, month: &Month) -> String {
+    let mut name = String::with_capacity(month.name().len());
+    for c in month.name().chars() {
+        if matches!(
+            c,
+            '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#'
+        ) {
+            name.push('\\');
+        }
+        name.push(c);
+    }
+
+    format!("{} {}", name, id.year)
+}
+
+fn date(date: Option<NaiveDate>) -> String {
+    date.map_or_else(String::new, |date| date.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::Calendar;
+
+    fn render(renderer: MarkdownRenderer, months: Vec<Month>) -> String {
+        let plan = Envelope::new(vec![2024], &Calendar::default(), months);
+        let mut out: Vec<u8> = Vec::new();
+        renderer.render(&plan, &mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    fn months_2024() -> Vec<Month> {
+        Calendar::default().year(2024).unwrap().into_months()
+    }
+
+    #[test]
+    fn one_table_per_month() {
+        let markdown = render(MarkdownRenderer::default(), months_2024());
+
+        assert!(markdown.starts_with(concat!(
+            "## January 2024\n",
+            "\n",
+            "| Week | Start | End | Start day | Days |\n",
+            "| --- | --- | --- | --- | ---: |\n",
+            "| 2024-M01-W1 | 2024-01-01 | 2024-01-07 | Mon | 7 |\n",
+        )));
+        assert!(markdown
+            .contains("| 2024-M01-W4 | 2024-01-22 | 2024-01-28 | Mon | 7 |\n\n## February 2024\n"));
+        assert!(markdown.ends_with("| 2024-M12-W5 | 2024-12-30 | 2024-12-31 | Mon | 2 |\n"));
+        assert_eq!(markdown.matches("## ").count(), 12);
+    }
+
+    #[test]
+    fn compact_table() {
+        let markdown = render(MarkdownRenderer { compact: true }, months_2024());
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        assert_eq!(lines[0], "| Month | Id | Weeks | Start | End | Days |");
+        assert_eq!(
+            lines[3],
+            "| February 2024 | 2024-M02 | 5 | 2024-01-29 | 2024-03-03 | 35 |"
+        );
+        assert_eq!(lines.len(), 2 + 12);
+    }
+
+    #[test]
+    fn escape_names() {
+        let weeks = months_2024()[0].weeks().to_vec();
+
+        let markdown = render(
+            MarkdownRenderer { compact: true },
+            vec![Month::new("Rent | *flat*", weeks)],
+        );
+
+        assert!(markdown.contains("| Rent \\| \\*flat\\* 2024 | 2024-M01 |"));
+    }
+}
@@ -0,0 +1,276 @@
+use core::iter::{FusedIterator, Peekable};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::Calendar;
+use crate::civil::CivilDate;
+use crate::strategy::Strategy;
+use crate::week::Week;
+
+/// A short first week and 5 full weeks, when every week starting in January goes to January.
+const MAX_WEEKS_IN_MONTH: usize = 6;
+
+/// A budget month: a name and the weeks it owns, in order.
+///
+/// With the default periods, there is one budget month per calendar month,
+/// named after it (`January`, `February`...).
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(transform = crate::id::month_id_schema))]
+pub struct Month {
+    pub(crate) name: String,
+    pub(crate) weeks: Vec<Week>,
+}
+
+impl Month {
+    /// A budget month named `name`, owning `weeks`, as built by a [`crate::PeriodStrategy`].
+    pub fn new(name: impl Into<String>, weeks: Vec<Week>) -> Month {
+        Month {
+            name: name.into(),
+            weeks,
+        }
+    }
+
+    /// The name of the budget month, or of its budget period.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The weeks owned by the budget month.
+    pub fn weeks(&self) -> &[Week] {
+        &self.weeks
+    }
+
+    /// The first day of the first week, `None` when the month has no week.
+    pub fn start_date(&self) -> Option<NaiveDate> {
+        self.weeks.first().map(|week| week.start_date)
+    }
+
+    /// The last day of the last week, `None` when the month has no week.
+    pub fn end_date(&self) -> Option<NaiveDate> {
+        self.weeks.last().map(|week| week.end_date)
+    }
+
+    /// [`Month::start_date`] as another date type, `None` when out of its range too.
+    pub fn start_date_as<D: CivilDate>(&self) -> Option<D> {
+        self.weeks.first()?.start_date_as()
+    }
+
+    /// [`Month::end_date`] as another date type, `None` when out of its range too.
+    pub fn end_date_as<D: CivilDate>(&self) -> Option<D> {
+        self.weeks.last()?.end_date_as()
+    }
+
+    /// How many days the budget month lasts.
+    pub fn days(&self) -> i64 {
+        self.weeks.iter().map(Week::days).sum()
+    }
+
+    /// Whether `date` is part of one of the weeks of the month.
+    pub fn contains(&self, date: impl CivilDate) -> bool {
+        let Some(date) = date.to_naive_date() else {
+            return false;
+        };
+
+        self.weeks.iter().any(|week| week.contains(date))
+    }
+}
+
+/// The index of the period owning `week`, `None` when its month is left out of every period.
+fn owning_period(
+    week: &Week,
+    owners: &[Option<usize>; 12],
+    strategy: Strategy,
+    calendar: &Calendar,
+) -> Option<usize> {
+    owners[strategy.month_of(week, calendar).number_from_month() as usize - 1]
+}
+
+pub(crate) fn split_in_months(
+    weeks: &[Week],
+    calendar: &Calendar,
+    strategy: Strategy,
+) -> Vec<Month> {
+    Months::new(weeks.iter().copied(), calendar, strategy)
+        .map(|(_, month)| month)
+        .collect()
+}
+
+/// Lazily groups consecutive weeks into budget months, yielded with their calendar year.
+///
+/// Weeks left out of every period are skipped. Built by
+/// [`Calendar::months`](crate::Calendar::months), which also accepts open-ended ranges of years.
+#[derive(Debug, Clone)]
+pub struct Months<'a, I: Iterator<Item = Week>> {
+    weeks: Peekable<I>,
+    calendar: &'a Calendar,
+    owners: [Option<usize>; 12],
+    strategy: Strategy,
+}
+
+impl<'a, I: Iterator<Item = Week>> Months<'a, I> {
+    pub(crate) fn new(weeks: I, calendar: &'a Calendar, strategy: Strategy) -> Months<'a, I> {
+        Months {
+            weeks: weeks.peekable(),
+            calendar,
+            owners: calendar.periods().owners(),
+            strategy,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Week>> Iterator for Months<'_, I> {
+    type Item = (i32, Month);
+
+    fn next(&mut self) -> Option<(i32, Month)> {
+        let (owners, strategy, calendar) = (self.owners, self.strategy, self.calendar);
+
+        let (first_week, index): (Week, usize) = loop {
+            let week: Week = self.weeks.next()?;
+
+            if let Some(index) = owning_period(&week, &owners, strategy, calendar) {
+                break (week, index);
+            }
+        };
+
+        let year: i32 = first_week.start_date.year();
+        let period = calendar.periods().get(index);
+
+        let mut weeks: Vec<Week> = Vec::with_capacity(period.months.len() * MAX_WEEKS_IN_MONTH);
+        weeks.push(first_week);
+        while let Some(week) = self.weeks.next_if(|week| {
+            week.start_date.year() == year
+                && owning_period(week, &owners, strategy, calendar) == Some(index)
+        }) {
+            weeks.push(week);
+        }
+
+        Some((
+            year,
+            Month {
+                name: period.name.clone(),
+                weeks,
+            },
+        ))
+    }
+}
+
+impl<I: FusedIterator<Item = Week>> FusedIterator for Months<'_, I> {}
+
+#[cfg(test)]
+mod test {
+    use chrono::Month::*;
+    use chrono::Weekday;
+
+    use super::*;
+    use crate::week::{define_weeks_in_year, Weeks};
+
+    #[test]
+    fn split_month_into_budget_weeks_four_weeks() {
+        let start_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let month = January;
+
+        let weeks: Vec<Week> = define_weeks_in_year(start_date, end_date, Weekday::Mon);
+
+        let result: Month = split_in_months(&weeks, &Calendar::default(), Strategy::Monthly)
+            .remove(month.number_from_month() as usize - 1);
+
+        assert_eq!(result.weeks.len(), 4);
+    }
+
+    #[test]
+    fn split_month_into_budget_weeks_five_weeks() {
+        let start_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let month = February;
+
+        let weeks: Vec<Week> = define_weeks_in_year(start_date, end_date, Weekday::Mon);
+
+        let result = split_in_months(&weeks, &Calendar::default(), Strategy::Monthly)
+            .remove(month.number_from_month() as usize - 1);
+
+        assert_eq!(result.weeks.len(), 5);
+    }
+
+    #[test]
+    fn months_across_years() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 11, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let weeks = Weeks::new(start_date, end_date, Weekday::Mon);
+        let calendar = Calendar::default();
+
+        let months: Vec<(i32, Month)> = Months::new(weeks, &calendar, Strategy::Monthly).collect();
+
+        assert_eq!(
+            months
+                .iter()
+                .map(|(year, month)| (*year, month.name(), month.weeks.len()))
+                .collect::<Vec<(i32, &str, usize)>>(),
+            vec![
+                (2023, "November", 5),
+                (2023, "December", 4),
+                (2024, "January", 4),
+                (2024, "February", 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_months_into_budget_weeks_1() {
+        let start_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        let weeks: Vec<Week> = define_weeks_in_year(start_date, end_date, Weekday::Mon);
+
+        let result: Vec<Month> = split_in_months(&weeks, &Calendar::default(), Strategy::Monthly);
+
+        assert_eq!(result.len(), 12);
+        assert_eq!(
+            result
+                .clone()
+                .into_iter()
+                .map(|month| month.weeks.len())
+                .collect::<Vec<usize>>(),
+            vec![4, 5, 4, 4, 5, 4, 4, 5, 4, 5, 4, 5]
+        );
+        assert_eq!(
+            result
+                .into_iter()
+                .map(|month| month.weeks.len())
+                .sum::<usize>(),
+            53
+        );
+    }
+
+    #[test]
+    fn split_months_into_budget_weeks_2() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+
+        let weeks: Vec<Week> = define_weeks_in_year(start_date, end_date, Weekday::Mon);
+
+        let result: Vec<Month> = split_in_months(&weeks, &Calendar::default(), Strategy::Monthly);
+
+        assert_eq!(result.len(), 12);
+        assert_eq!(
+            result
+                .clone()
+                .into_iter()
+                .map(|month| month.weeks.len())
+                .collect::<Vec<usize>>(),
+            vec![5, 4, 5, 4, 4, 5, 4, 5, 4, 4, 5, 4]
+        );
+        assert_eq!(
+            result
+                .into_iter()
+                .map(|month| month.weeks.len())
+                .sum::<usize>(),
+            53
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+use chrono::{Datelike, Month as ChronoMonth, NaiveDate};
+
+use crate::week::{last_day_month, Week};
+
+/// How a week overlapping two calendar months is given to one of them.
+///
+/// Whatever the rule, the first week of a year always goes to January
+/// and the last one to December.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Ownership {
+    /// The month owning at least 4 days of the week.
+    #[default]
+    Majority,
+    /// The month of the first day of the week.
+    FirstDay,
+    /// The month of the last day of the week.
+    LastDay,
+}
+
+impl fmt::Display for Ownership {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ownership::Majority => write!(f, "majority"),
+            Ownership::FirstDay => write!(f, "first-day"),
+            Ownership::LastDay => write!(f, "last-day"),
+        }
+    }
+}
+
+impl Ownership {
+    /// The calendar month `week` is given to.
+    pub fn month_of(&self, week: &Week) -> ChronoMonth {
+        owning_month(week, *self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for Ownership {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "majority" => Ok(Ownership::Majority),
+            "first-day" => Ok(Ownership::FirstDay),
+            "last-day" => Ok(Ownership::LastDay),
+            _ => Err(format!(
+                "unknown ownership rule {}, expected majority, first-day or last-day",
+                s
+            )),
+        }
+    }
+}
+
+fn is_day_owned_by_month(day: NaiveDate, month: ChronoMonth) -> bool {
+    day.month() == month.number_from_month()
+}
+
+fn how_many_days_in_week_owned_by_month(week: &Week, month: ChronoMonth) -> u8 {
+    let first_date: NaiveDate = week.start_date;
+    let last_date: NaiveDate = week.end_date;
+
+    // A week is never longer than a month, it can only overlap two of them.
+    let count: i64 = match (
+        is_day_owned_by_month(first_date, month),
+        is_day_owned_by_month(last_date, month),
+    ) {
+        (true, true) => (last_date - first_date).num_days() + 1,
+        (true, false) => (last_day_month(first_date.year(), month) - first_date).num_days() + 1,
+        (false, true) => last_date.day() as i64,
+        (false, false) => 0,
+    };
+
+    count as u8
+}
+
+fn month_of(date: NaiveDate) -> ChronoMonth {
+    ChronoMonth::try_from(date.month() as u8).unwrap()
+}
+
+fn owning_month(week: &Week, ownership: Ownership) -> ChronoMonth {
+    let first_date = week.start_date;
+    if first_date.day() == 1 && first_date.month() == 1 {
+        return ChronoMonth::January;
+    }
+
+    let last_date = week.end_date;
+    if last_date.day() == 31 && last_date.month() == 12 {
+        return ChronoMonth::December;
+    }
+
+    let first_month: ChronoMonth = month_of(first_date);
+
+    match ownership {
+        Ownership::Majority => {
+            let number_of_days: u8 = how_many_days_in_week_owned_by_month(week, first_month);
+
+            match number_of_days {
+                0..=3 => month_of(last_date),
+                4..=u8::MAX => first_month,
+            }
+        }
+        Ownership::FirstDay => first_month,
+        Ownership::LastDay => month_of(last_date),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Weekday;
+
+    use super::*;
+    use crate::week::{define_first_week, define_last_week, define_week};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ownership_from_str() {
+        assert_eq!("majority".parse(), Ok(Ownership::Majority));
+        assert_eq!("first-day".parse(), Ok(Ownership::FirstDay));
+        assert_eq!("last-day".parse(), Ok(Ownership::LastDay));
+        assert!("most".parse::<Ownership>().is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ownership_display() {
+        for ownership in [Ownership::Majority, Ownership::FirstDay, Ownership::LastDay] {
+            assert_eq!(ownership.to_string().parse(), Ok(ownership));
+        }
+    }
+
+    #[test]
+    fn month_of_a_week() {
+        // From Monday 2024-01-29 to Sunday 2024-02-04.
+        let week: Week = define_week(NaiveDate::from_ymd_opt(2024, 1, 29).unwrap());
+
+        assert_eq!(Ownership::Majority.month_of(&week), ChronoMonth::February);
+        assert_eq!(Ownership::FirstDay.month_of(&week), ChronoMonth::January);
+        assert_eq!(Ownership::LastDay.month_of(&week), ChronoMonth::February);
+    }
+
+    #[test]
+    fn is_day_owned_by_month_positive() {
+        let date = NaiveDate::from_ymd_opt(2022, 3, 28).unwrap();
+        let month = ChronoMonth::March;
+
+        let result: bool = is_day_owned_by_month(date, month);
+
+        assert!(result);
+    }
+
+    #[test]
+    fn is_day_owned_by_month_negative() {
+        let date = NaiveDate::from_ymd_opt(2022, 4, 28).unwrap();
+        let month = ChronoMonth::March;
+
+        let result: bool = is_day_owned_by_month(date, month);
+
+        assert!(!result);
+    }
+
+    #[test]
+    fn how_many_days_week_owned_by_month_four_days() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 3, 28).unwrap();
+        let week: Week = define_week(start_date);
+        let month = ChronoMonth::March;
+
+        let result: u8 = how_many_days_in_week_owned_by_month(&week, month);
+
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn how_many_days_week_owned_by_month_three_days() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 3, 28).unwrap();
+        let week: Week = define_week(start_date);
+        let month = ChronoMonth::April;
+
+        let result: u8 = how_many_days_in_week_owned_by_month(&week, month);
+
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn how_many_days_week_owned_by_month_seven_days() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap();
+        let week: Week = define_week(start_date);
+        let month = ChronoMonth::April;
+
+        let result: u8 = how_many_days_in_week_owned_by_month(&week, month);
+
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn owning_month_three_days() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 3, 28).unwrap();
+        let week: Week = define_week(start_date);
+        let month = ChronoMonth::April;
+
+        let result = owning_month(&week, Ownership::Majority) == month;
+
+        assert!(!result);
+    }
+
+    #[test]
+    fn owning_month_four_days() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 3, 28).unwrap();
+        let week: Week = define_week(start_date);
+        let month = ChronoMonth::March;
+
+        let result = owning_month(&week, Ownership::Majority) == month;
+
+        assert!(result);
+    }
+
+    #[test]
+    fn owning_month_seven_days() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap();
+        let week: Week = define_week(start_date);
+        let month = ChronoMonth::April;
+
+        let result = owning_month(&week, Ownership::Majority) == month;
+
+        assert!(result);
+    }
+
+    #[test]
+    fn owning_month_first_week_in_year() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let week: Week = define_first_week(start_date, Weekday::Mon);
+        let month = ChronoMonth::January;
+
+        let result = owning_month(&week, Ownership::Majority) == month;
+
+        assert!(result);
+    }
+
+    #[test]
+    fn owning_month_last_week_in_year() {
+        let end_date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let week: Week = define_last_week(end_date, Weekday::Mon);
+        let month = ChronoMonth::December;
+
+        let result = owning_month(&week, Ownership::Majority) == month;
+
+        assert!(result);
+    }
+}
@@ -0,0 +1,283 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use chrono::Month as ChronoMonth;
+use chrono::Month::*;
+
+use crate::error::CalendarError;
+
+/// A budget period gets every week owned by one of its calendar months.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Period {
+    pub(crate) name: String,
+    #[cfg_attr(feature = "schema", schemars(schema_with = "calendar_months_schema"))]
+    pub(crate) months: Vec<ChronoMonth>,
+}
+
+/// Calendar months are written with their English names by chrono.
+#[cfg(feature = "schema")]
+fn calendar_months_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": "array",
+        "items": {
+            "type": "string",
+            "enum": [
+                "January", "February", "March", "April", "May", "June", "July",
+                "August", "September", "October", "November", "December"
+            ]
+        }
+    })
+}
+
+impl Period {
+    /// A period named `name`, getting the weeks of `months`.
+    ///
+    /// The months are checked when the period is added to [`Periods`].
+    pub fn new(name: impl Into<String>, months: Vec<ChronoMonth>) -> Period {
+        Period {
+            name: name.into(),
+            months,
+        }
+    }
+
+    /// The name of the period, used as the name of its budget month.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The calendar months of the period, in order.
+    pub fn months(&self) -> &[ChronoMonth] {
+        &self.months
+    }
+
+    /// The first calendar month of the period.
+    pub fn first_month(&self) -> ChronoMonth {
+        *self.months.first().unwrap()
+    }
+
+    /// The last calendar month of the period.
+    pub fn last_month(&self) -> ChronoMonth {
+        *self.months.last().unwrap()
+    }
+}
+
+impl From<ChronoMonth> for Period {
+    fn from(month: ChronoMonth) -> Self {
+        Period {
+            name: month.name().to_string(),
+            months: vec![month],
+        }
+    }
+}
+
+/// The budget periods of a year, in calendar order.
+///
+/// Each period covers consecutive calendar months and two periods never share a month.
+/// A calendar month can be left out, its weeks don't belong to any period then.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Periods(Vec<Period>);
+
+/// One budget period for each calendar month.
+impl Default for Periods {
+    fn default() -> Self {
+        let months: Vec<ChronoMonth> = vec![
+            January, February, March, April, May, June, July, August, September, October, November,
+            December,
+        ];
+
+        Periods(months.into_iter().map(Period::from).collect())
+    }
+}
+
+impl Periods {
+    /// Checks the rules above, [`CalendarError::InvalidPeriods`] explains the first one broken.
+    pub fn new(periods: Vec<Period>) -> Result<Periods, CalendarError> {
+        if periods.is_empty() {
+            return Err(CalendarError::InvalidPeriods(String::from(
+                "at least one budget period is required",
+            )));
+        }
+
+        for (index, period) in periods.iter().enumerate() {
+            if period.name.trim().is_empty() {
+                return Err(CalendarError::InvalidPeriods(format!(
+                    "budget period #{} has no name",
+                    index + 1
+                )));
+            }
+
+            if periods[..index]
+                .iter()
+                .any(|other| other.name == period.name)
+            {
+                return Err(CalendarError::InvalidPeriods(format!(
+                    "budget period {} is defined twice",
+                    period.name
+                )));
+            }
+
+            if period.months.is_empty() {
+                return Err(CalendarError::InvalidPeriods(format!(
+                    "budget period {} has no month",
+                    period.name
+                )));
+            }
+
+            for pair in period.months.windows(2) {
+                if pair[0] == December || pair[0].succ() != pair[1] {
+                    return Err(CalendarError::InvalidPeriods(format!(
+                        "the months of budget period {} must be consecutive ({} is followed by {})",
+                        period.name,
+                        pair[0].name(),
+                        pair[1].name()
+                    )));
+                }
+            }
+
+            if let Some(previous) = index.checked_sub(1).map(|previous| &periods[previous]) {
+                if previous.last_month().number_from_month()
+                    >= period.first_month().number_from_month()
+                {
+                    return Err(CalendarError::InvalidPeriods(format!(
+                        "budget period {} must start after the end of {}",
+                        period.name, previous.name
+                    )));
+                }
+            }
+        }
+
+        Ok(Periods(periods))
+    }
+
+    /// For each calendar month, the index of the period it belongs to.
+    pub(crate) fn owners(&self) -> [Option<usize>; 12] {
+        let mut owners: [Option<usize>; 12] = [None; 12];
+
+        for (index, period) in self.0.iter().enumerate() {
+            for month in &period.months {
+                owners[month.number_from_month() as usize - 1] = Some(index);
+            }
+        }
+
+        owners
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Period {
+        &self.0[index]
+    }
+
+    /// The periods, in calendar order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Period> {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn period(name: &str, months: Vec<ChronoMonth>) -> Period {
+        Period {
+            name: name.to_string(),
+            months,
+        }
+    }
+
+    #[test]
+    fn default_periods() {
+        let periods = Periods::default();
+
+        assert_eq!(periods.iter().count(), 12);
+        assert_eq!(
+            periods.iter().next().unwrap(),
+            &period("January", vec![January])
+        );
+    }
+
+    #[test]
+    fn bi_monthly_periods() {
+        let periods = Periods::new(vec![
+            period("Winter", vec![January, February]),
+            period("Spring", vec![March, April]),
+        ])
+        .unwrap();
+
+        assert_eq!(periods.iter().count(), 2);
+        assert_eq!(periods.iter().last().unwrap().last_month(), April);
+    }
+
+    #[test]
+    fn owners_of_bi_monthly_periods() {
+        let periods = Periods::new(vec![
+            period("Winter", vec![January, February]),
+            period("Summer", vec![July, August]),
+        ])
+        .unwrap();
+
+        let owners = periods.owners();
+
+        assert_eq!(owners[0], Some(0));
+        assert_eq!(owners[1], Some(0));
+        assert_eq!(owners[2], None);
+        assert_eq!(owners[6], Some(1));
+        assert_eq!(owners[11], None);
+    }
+
+    #[test]
+    fn no_period() {
+        assert!(Periods::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn period_without_month() {
+        assert!(Periods::new(vec![period("Nothing", vec![])]).is_err());
+    }
+
+    #[test]
+    fn period_without_name() {
+        assert!(Periods::new(vec![period(" ", vec![January])]).is_err());
+    }
+
+    #[test]
+    fn period_defined_twice() {
+        let periods = vec![
+            period("Winter", vec![January]),
+            period("Winter", vec![March]),
+        ];
+
+        assert!(Periods::new(periods).is_err());
+    }
+
+    #[test]
+    fn period_with_gap() {
+        assert!(Periods::new(vec![period("Winter", vec![January, March])]).is_err());
+    }
+
+    #[test]
+    fn period_across_years() {
+        assert!(Periods::new(vec![period("Holidays", vec![December, January])]).is_err());
+    }
+
+    #[test]
+    fn periods_overlapping() {
+        let periods = vec![
+            period("Winter", vec![January, February]),
+            period("Spring", vec![February, March]),
+        ];
+
+        assert!(Periods::new(periods).is_err());
+    }
+
+    #[test]
+    fn periods_out_of_order() {
+        let periods = vec![
+            period("Spring", vec![March]),
+            period("Winter", vec![January]),
+        ];
+
+        assert!(Periods::new(periods).is_err());
+    }
+}
@@ -0,0 +1,288 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use chrono::Weekday;
+
+use crate::calendar::{Calendar, CalendarBuilder};
+use crate::id::{month_ids, MonthId};
+use crate::month::Month;
+use crate::ownership::Ownership;
+use crate::periods::Period;
+use crate::strategy::Strategy;
+
+/// The version of the [`Envelope`] format, bumped on every incompatible change.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Budget months saved with what generated them.
+///
+/// ```json
+/// {
+///   "schema_version": 1,
+///   "tool_version": "1.0.1",
+///   "years": [2024],
+///   "week_start": "Mon",
+///   "ownership": "majority",
+///   "strategy": "monthly",
+///   "periods": [{ "name": "January", "months": ["January"] }, ...],
+///   "months": [{ "id": "2024-M01", "name": "January", "weeks": [...] }, ...]
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "schema",
+    schemars(description = "Budget months saved with what generated them.")
+)]
+pub struct Envelope {
+    pub schema_version: u32,
+    /// The version of budget_planner which wrote the plan.
+    pub tool_version: String,
+    pub years: Vec<i32>,
+    pub week_start: Weekday,
+    pub ownership: Ownership,
+    /// Missing from the plans written before it could be chosen.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strategy: Strategy,
+    /// The budget periods the years were split into.
+    pub periods: Vec<Period>,
+    /// Written with the [`crate::MonthId`] of each month and the [`crate::WeekId`] of each week.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::id::serialize_months")
+    )]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "The budget months, with the id of each month and of each week.")
+    )]
+    pub months: Vec<Month>,
+}
+
+impl Envelope {
+    /// Wraps the budget months of `years`, split with `calendar`.
+    pub fn new(years: Vec<i32>, calendar: &Calendar, months: Vec<Month>) -> Envelope {
+        Envelope {
+            schema_version: SCHEMA_VERSION,
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            years,
+            week_start: calendar.week_start(),
+            ownership: calendar.ownership(),
+            strategy: calendar.strategy(),
+            periods: calendar.periods().iter().cloned().collect(),
+            months,
+        }
+    }
+
+    /// A builder with the options the plan was generated with.
+    pub fn calendar_builder(&self) -> CalendarBuilder {
+        CalendarBuilder::new()
+            .week_start(self.week_start)
+            .ownership(self.ownership)
+            .strategy(self.strategy)
+            .periods(self.periods.clone())
+    }
+
+    /// The id of each budget month, counted from 1 again in every year.
+    pub fn month_ids(&self) -> Vec<MonthId> {
+        let first_year: i32 = self.years.first().copied().unwrap_or_default();
+
+        month_ids(&self.months, first_year)
+    }
+}
+
+/// A saved plan, either the bare list of budget months or an [`Envelope`].
+///
+/// Deserializing accepts both forms, so older files keep loading.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "schema",
+    schemars(
+        description = "A saved plan, either the bare list of budget months or an envelope with what generated them. Older files are bare lists."
+    )
+)]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Plan {
+    Versioned(Envelope),
+    Bare(
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::id::serialize_months")
+        )]
+        Vec<Month>,
+    ),
+}
+
+impl Plan {
+    /// The budget months, whatever the form.
+    pub fn months(&self) -> &[Month] {
+        match self {
+            Plan::Versioned(envelope) => &envelope.months,
+            Plan::Bare(months) => months,
+        }
+    }
+
+    /// The envelope, `None` for a bare list of months.
+    pub fn envelope(&self) -> Option<&Envelope> {
+        match self {
+            Plan::Versioned(envelope) => Some(envelope),
+            Plan::Bare(_) => None,
+        }
+    }
+
+    /// The JSON Schema of a saved plan, in both forms.
+    #[cfg(feature = "schema")]
+    pub fn json_schema() -> schemars::Schema {
+        schemars::schema_for!(Plan)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+    use chrono::Month::*;
+
+    use super::*;
+
+    #[test]
+    fn envelope_of_a_year() {
+        let calendar = CalendarBuilder::new()
+            .week_start(Weekday::Sun)
+            .periods(vec![Period::new("Winter", vec![January, February])])
+            .build()
+            .unwrap();
+        let months = calendar.year(2024).unwrap().into_months();
+
+        let envelope = Envelope::new(vec![2024], &calendar, months.clone());
+
+        assert_eq!(envelope.schema_version, SCHEMA_VERSION);
+        assert_eq!(envelope.tool_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(envelope.week_start, Weekday::Sun);
+        assert_eq!(envelope.periods.len(), 1);
+        assert_eq!(envelope.calendar_builder().build(), Ok(calendar));
+        assert_eq!(Plan::Versioned(envelope).months(), months.as_slice());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn load_both_forms() {
+        let calendar = Calendar::default();
+        let months = calendar.year(2024).unwrap().into_months();
+        let envelope = Envelope::new(vec![2024], &calendar, months.clone());
+
+        let bare: Plan = serde_json::from_str(&serde_json::to_string(&months).unwrap()).unwrap();
+        let versioned: Plan =
+            serde_json::from_str(&serde_json::to_string(&envelope).unwrap()).unwrap();
+
+        assert_eq!(bare, Plan::Bare(months));
+        assert_eq!(versioned, Plan::Versioned(envelope));
+    }
+
+    #[cfg(all(feature = "schema", not(target_arch = "wasm32")))]
+    #[test]
+    fn plans_match_the_schema() {
+        use crate::year::supported_years;
+
+        let schema = serde_json::to_value(Plan::json_schema()).unwrap();
+        let validator = jsonschema::options()
+            .should_validate_formats(true)
+            .build(&schema)
+            .unwrap();
+        let calendars = [
+            Calendar::default(),
+            CalendarBuilder::new()
+                .week_start(Weekday::Sun)
+                .ownership(Ownership::FirstDay)
+                .periods(vec![
+                    Period::new("Winter", vec![January, February, March]),
+                    Period::new("Summer", vec![June, July, August]),
+                ])
+                .build()
+                .unwrap(),
+        ];
+        let years = (1800..=2200).chain([*supported_years().start(), *supported_years().end()]);
+
+        for year in years {
+            for calendar in &calendars {
+                let months = calendar.year(year).unwrap().into_months();
+                let envelope = Envelope::new(vec![year], calendar, months.clone());
+
+                for plan in [
+                    serde_json::to_value(Plan::Bare(months)).unwrap(),
+                    serde_json::to_value(&envelope).unwrap(),
+                ] {
+                    if let Err(error) = validator.validate(&plan) {
+                        panic!("plan of {} doesn't match the schema: {}", year, error);
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "schema", not(target_arch = "wasm32")))]
+    #[test]
+    fn schema_rejects_other_plans() {
+        let schema = serde_json::to_value(Plan::json_schema()).unwrap();
+        let validator = jsonschema::validator_for(&schema).unwrap();
+
+        let missing_end = serde_json::json!([{
+            "name": "January",
+            "weeks": [{ "start_date": "2024-01-01", "start_day": "Mon" }]
+        }]);
+        let unknown_ownership = serde_json::json!({
+            "schema_version": 1,
+            "tool_version": "1.0.1",
+            "years": [2024],
+            "week_start": "Mon",
+            "ownership": "most",
+            "periods": [],
+            "months": []
+        });
+
+        let wrong_id = serde_json::json!([{
+            "id": "2024-02",
+            "name": "February",
+            "weeks": []
+        }]);
+
+        assert!(!validator.is_valid(&missing_end));
+        assert!(!validator.is_valid(&wrong_id));
+        assert!(!validator.is_valid(&unknown_ownership));
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn schema_descriptions_are_plain_text() {
+        let schema: String = serde_json::to_string(&Plan::json_schema()).unwrap();
+
+        assert!(!schema.contains("[`"), "{}", schema);
+        assert!(!schema.contains("```"), "{}", schema);
+    }
+
+    #[test]
+    fn month_ids_of_years() {
+        let calendar = Calendar::default();
+        let months: Vec<Month> = calendar
+            .months(2023..=2024)
+            .unwrap()
+            .map(|(_, month)| month)
+            .collect();
+
+        let ids = Envelope::new(vec![2023, 2024], &calendar, months).month_ids();
+
+        assert_eq!(ids.len(), 24);
+        assert_eq!(ids[11], MonthId::new(2023, 12));
+        assert_eq!(ids[12], MonthId::new(2024, 1));
+    }
+
+    #[test]
+    fn bare_plan() {
+        let months = Calendar::default().year(2024).unwrap().into_months();
+
+        let plan = Plan::Bare(months.clone());
+
+        assert_eq!(plan.months(), months.as_slice());
+        assert_eq!(plan.envelope(), None);
+    }
+}
@@ -0,0 +1,197 @@
+//! The Python module, built with maturin (see `pyproject.toml`).
+//!
+//! Dates are `datetime.date` objects, weekdays, ownership rules and strategies are the
+//! strings of the command line (`"Sun"`, `"first-day"`, `"4-4-5"`).
+
+use chrono::{Month as ChronoMonth, NaiveDate, Weekday};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::calendar::{Calendar, CalendarBuilder};
+use crate::month::Month;
+use crate::ownership::Ownership;
+use crate::periods::Period;
+use crate::strategy::Strategy;
+use crate::week::Week;
+
+/// A budget week, both days included.
+#[pyclass(name = "Week", module = "budget_planner", frozen, eq)]
+#[derive(PartialEq)]
+struct PyWeek(Week);
+
+#[pymethods]
+impl PyWeek {
+    #[getter]
+    fn start_date(&self) -> NaiveDate {
+        self.0.start_date()
+    }
+
+    #[getter]
+    fn end_date(&self) -> NaiveDate {
+        self.0.end_date()
+    }
+
+    /// The weekday of `start_date`, like `"Mon"`.
+    #[getter]
+    fn start_day(&self) -> String {
+        self.0.start_day().to_string()
+    }
+
+    #[getter]
+    fn days(&self) -> i64 {
+        self.0.days()
+    }
+
+    fn __contains__(&self, date: NaiveDate) -> bool {
+        self.0.contains(date)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Week({}, {})", self.0.start_date(), self.0.end_date())
+    }
+}
+
+/// A budget month, named after its calendar month or budget period.
+#[pyclass(name = "Month", module = "budget_planner", frozen, eq)]
+#[derive(PartialEq)]
+struct PyMonth(Month);
+
+#[pymethods]
+impl PyMonth {
+    #[getter]
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    #[getter]
+    fn weeks(&self) -> Vec<PyWeek> {
+        self.0.weeks().iter().copied().map(PyWeek).collect()
+    }
+
+    #[getter]
+    fn start_date(&self) -> Option<NaiveDate> {
+        self.0.start_date()
+    }
+
+    #[getter]
+    fn end_date(&self) -> Option<NaiveDate> {
+        self.0.end_date()
+    }
+
+    #[getter]
+    fn days(&self) -> i64 {
+        self.0.days()
+    }
+
+    fn __contains__(&self, date: NaiveDate) -> bool {
+        self.0.contains(date)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.weeks().len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Month({:?}, {} weeks)", self.0.name(), self.0.weeks().len())
+    }
+}
+
+/// The options used to split years, all optional:
+///
+/// `Calendar(week_start="Sun", ownership="first-day", strategy="4-4-5", periods=[("Winter", [1, 2, 3])])`
+#[pyclass(name = "Calendar", module = "budget_planner", frozen)]
+struct PyCalendar(Calendar);
+
+#[pymethods]
+impl PyCalendar {
+    #[new]
+    #[pyo3(signature = (week_start = None, ownership = None, strategy = None, periods = None))]
+    fn new(
+        week_start: Option<&str>,
+        ownership: Option<&str>,
+        strategy: Option<&str>,
+        periods: Option<Vec<(String, Vec<u8>)>>,
+    ) -> PyResult<PyCalendar> {
+        let mut builder = CalendarBuilder::new();
+
+        if let Some(week_start) = week_start {
+            let week_start: Weekday = week_start
+                .parse()
+                .map_err(|_| PyValueError::new_err(format!("unknown weekday {}", week_start)))?;
+            builder = builder.week_start(week_start);
+        }
+        if let Some(ownership) = ownership {
+            let ownership: Ownership = ownership.parse().map_err(PyValueError::new_err)?;
+            builder = builder.ownership(ownership);
+        }
+        if let Some(strategy) = strategy {
+            let strategy: Strategy = strategy.parse().map_err(PyValueError::new_err)?;
+            builder = builder.strategy(strategy);
+        }
+        if let Some(periods) = periods {
+            builder = builder.periods(
+                periods
+                    .into_iter()
+                    .map(|(name, months)| Ok(Period::new(name, calendar_months(months)?)))
+                    .collect::<PyResult<Vec<Period>>>()?,
+            );
+        }
+
+        builder
+            .build()
+            .map(PyCalendar)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// The budget months of `year`.
+    fn year(&self, year: i32) -> PyResult<Vec<PyMonth>> {
+        let months: Vec<Month> = self
+            .0
+            .year(year)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?
+            .into_months();
+
+        Ok(months.into_iter().map(PyMonth).collect())
+    }
+
+    /// The budget month (`None` when left out of every period) and the week of `date`.
+    fn lookup(&self, date: NaiveDate) -> PyResult<(Option<PyMonth>, PyWeek)> {
+        let year = self
+            .0
+            .year(chrono::Datelike::year(&date))
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+        // Every date of a year is part of one of its weeks.
+        let week: Week = *year.week_of(date).unwrap();
+
+        Ok((year.month_of(date).cloned().map(PyMonth), PyWeek(week)))
+    }
+}
+
+fn calendar_months(months: Vec<u8>) -> PyResult<Vec<ChronoMonth>> {
+    months
+        .into_iter()
+        .map(|month| {
+            ChronoMonth::try_from(month).map_err(|_| {
+                PyValueError::new_err(format!("invalid month {}, expected 1 to 12", month))
+            })
+        })
+        .collect()
+}
+
+/// The budget months of `year` with the default calendar.
+#[pyfunction]
+fn budget_year(year: i32) -> PyResult<Vec<PyMonth>> {
+    PyCalendar(Calendar::default()).year(year)
+}
+
+/// Splits years into budget months.
+#[pymodule]
+fn budget_planner(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyWeek>()?;
+    module.add_class::<PyMonth>()?;
+    module.add_class::<PyCalendar>()?;
+    module.add_function(wrap_pyfunction!(budget_year, module)?)?;
+
+    Ok(())
+}
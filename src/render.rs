@@ -0,0 +1,203 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::csv::CsvRenderer;
+use crate::ics::IcsRenderer;
+#[cfg(feature = "json")]
+use crate::id::IdentifiedMonths;
+use crate::markdown::MarkdownRenderer;
+use crate::plan::Envelope;
+
+/// Writes budget months in an output format.
+///
+/// A renderer gets an [`Envelope`], so it can show the years and the options the months
+/// were split with along the months themselves.
+///
+/// ```
+/// use std::io::{self, Write};
+///
+/// use budget_planner::{Calendar, Envelope, Renderer, Renderers};
+///
+/// /// One line per budget month.
+/// struct Names;
+///
+/// impl Renderer for Names {
+///     fn render(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+///         for month in &plan.months {
+///             writeln!(out, "{}", month.name())?;
+///         }
+///
+///         Ok(())
+///     }
+/// }
+///
+/// let calendar = Calendar::default();
+/// let plan = Envelope::new(vec![2024], &calendar, calendar.year(2024).unwrap().into_months());
+///
+/// let mut renderers = Renderers::default();
+/// renderers.register("names", Names);
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// renderers.get("names").unwrap().render(&plan, &mut out).unwrap();
+///
+/// assert!(out.starts_with(b"January\nFebruary\n"));
+/// ```
+pub trait Renderer {
+    fn render(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()>;
+}
+
+/// The renderers, by the name selecting them with `--format`.
+///
+/// [`Renderers::default`] holds the formats shipped with the crate, with their default options.
+pub struct Renderers {
+    renderers: Vec<(String, Box<dyn Renderer>)>,
+}
+
+impl Renderers {
+    /// An empty registry.
+    pub fn new() -> Renderers {
+        Renderers {
+            renderers: Vec::new(),
+        }
+    }
+
+    /// Adds `renderer` as `name`, replacing the renderer already registered under that name.
+    pub fn register(&mut self, name: impl Into<String>, renderer: impl Renderer + 'static) {
+        let name: String = name.into();
+        let renderer: Box<dyn Renderer> = Box::new(renderer);
+
+        match self.renderers.iter_mut().find(|(other, _)| *other == name) {
+            Some((_, registered)) => *registered = renderer,
+            None => self.renderers.push((name, renderer)),
+        }
+    }
+
+    /// The renderer registered as `name`.
+    pub fn get(&self, name: &str) -> Option<&dyn Renderer> {
+        self.renderers
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, renderer)| renderer.as_ref())
+    }
+
+    /// The names of the renderers, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.renderers.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+impl Default for Renderers {
+    fn default() -> Self {
+        let mut renderers = Renderers::new();
+        #[cfg(feature = "json")]
+        renderers.register("json", JsonRenderer::default());
+        renderers.register("csv", CsvRenderer::default());
+        renderers.register("ics", IcsRenderer::default());
+        renderers.register("markdown", MarkdownRenderer::default());
+
+        renderers
+    }
+}
+
+impl fmt::Debug for Renderers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+/// The budget months as a JSON array on a single line, or the whole [`Envelope`].
+///
+/// Months and weeks are written with their [`crate::MonthId`] and [`crate::WeekId`].
+#[cfg(feature = "json")]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct JsonRenderer {
+    /// Writes the envelope instead of the bare months.
+    pub envelope: bool,
+}
+
+#[cfg(feature = "json")]
+impl Renderer for JsonRenderer {
+    fn render(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+        if self.envelope {
+            serde_json::to_writer(&mut *out, plan)?;
+        } else {
+            serde_json::to_writer(&mut *out, &IdentifiedMonths(&plan.months))?;
+        }
+
+        writeln!(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::Calendar;
+
+    fn plan() -> Envelope {
+        let calendar = Calendar::default();
+
+        Envelope::new(
+            vec![2024],
+            &calendar,
+            calendar.year(2024).unwrap().into_months(),
+        )
+    }
+
+    fn render(renderer: &dyn Renderer) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        renderer.render(&plan(), &mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    struct Count;
+
+    impl Renderer for Count {
+        fn render(&self, plan: &Envelope, out: &mut dyn Write) -> io::Result<()> {
+            writeln!(out, "{}", plan.months.len())
+        }
+    }
+
+    #[test]
+    fn register_a_renderer() {
+        let mut renderers = Renderers::new();
+        assert!(renderers.get("count").is_none());
+
+        renderers.register("count", Count);
+
+        assert_eq!(render(renderers.get("count").unwrap()), "12\n");
+        assert_eq!(renderers.names().collect::<Vec<&str>>(), vec!["count"]);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn replace_a_renderer() {
+        let mut renderers = Renderers::default();
+
+        renderers.register("json", Count);
+
+        assert_eq!(render(renderers.get("json").unwrap()), "12\n");
+        assert_eq!(renderers.names().count(), 4);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_months() {
+        let json = render(&JsonRenderer::default());
+
+        assert!(
+            json.starts_with("[{\"id\":\"2024-M01\",\"name\":\"January\",\"weeks\":[{\"id\":\"2024-M01-W1\",\"start_date\":\"2024-01-01\"")
+        );
+        assert!(json.ends_with("}]}]\n"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_envelope() {
+        let json = render(&JsonRenderer { envelope: true });
+
+        assert!(json.starts_with("{\"schema_version\":1,"));
+        let parsed: Envelope = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, plan());
+    }
+}
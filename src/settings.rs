@@ -0,0 +1,159 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use chrono::Weekday;
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+use crate::calendar::CalendarBuilder;
+use crate::dates::parse_timezone;
+use crate::ownership::Ownership;
+use crate::periods::Period;
+use crate::strategy::Strategy;
+
+/// The settings read from the TOML file given with `--config`.
+///
+/// ```toml
+/// timezone = "Europe/Paris"
+/// week_start = "Sun"
+/// ownership = "first-day"
+/// strategy = "4-4-5"
+///
+/// [[periods]]
+/// name = "Winter"
+/// months = ["January", "February"]
+/// ```
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Settings {
+    periods: Option<Vec<Period>>,
+    timezone: Option<String>,
+    week_start: Option<Weekday>,
+    ownership: Option<Ownership>,
+    strategy: Option<Strategy>,
+}
+
+impl Settings {
+    pub(crate) fn load(path: &Path) -> Result<Settings, Box<dyn Error>> {
+        let content: String = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+
+        Settings::parse(&content).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    fn parse(content: &str) -> Result<Settings, Box<dyn Error>> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// A builder with the calendar options of the file, the other ones keep their default.
+    pub(crate) fn calendar_builder(&self) -> CalendarBuilder {
+        let mut builder = CalendarBuilder::new();
+
+        if let Some(periods) = &self.periods {
+            builder = builder.periods(periods.clone());
+        }
+        if let Some(week_start) = self.week_start {
+            builder = builder.week_start(week_start);
+        }
+        if let Some(ownership) = self.ownership {
+            builder = builder.ownership(ownership);
+        }
+        if let Some(strategy) = self.strategy {
+            builder = builder.strategy(strategy);
+        }
+
+        builder
+    }
+
+    pub(crate) fn timezone(&self) -> Result<Option<Tz>, Box<dyn Error>> {
+        match &self.timezone {
+            Some(name) => Ok(Some(parse_timezone(name)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_empty_settings() {
+        let settings = Settings::parse("").unwrap();
+
+        assert_eq!(settings, Settings::default());
+        assert_eq!(settings.calendar_builder(), CalendarBuilder::new());
+    }
+
+    #[test]
+    fn parse_periods() {
+        let settings = Settings::parse(
+            r#"
+            [[periods]]
+            name = "Winter"
+            months = ["January", "Feb"]
+
+            [[periods]]
+            name = "Spring"
+            months = ["March", "April"]
+            "#,
+        )
+        .unwrap();
+
+        let calendar = settings.calendar_builder().build().unwrap();
+        let names: Vec<&str> = calendar.periods().iter().map(Period::name).collect();
+
+        assert_eq!(names, vec!["Winter", "Spring"]);
+    }
+
+    #[test]
+    fn parse_calendar_options() {
+        let settings = Settings::parse(
+            r#"
+            week_start = "Sun"
+            ownership = "last-day"
+            strategy = "5-4-4"
+            "#,
+        )
+        .unwrap();
+
+        let calendar = settings.calendar_builder().build().unwrap();
+
+        assert_eq!(calendar.week_start(), Weekday::Sun);
+        assert_eq!(calendar.ownership(), Ownership::LastDay);
+        assert_eq!(calendar.strategy(), Strategy::FiveFourFour);
+    }
+
+    #[test]
+    fn parse_timezone_setting() {
+        let settings = Settings::parse("timezone = \"Europe/Paris\"").unwrap();
+
+        assert_eq!(settings.timezone().unwrap(), Some(chrono_tz::Europe::Paris));
+    }
+
+    #[test]
+    fn parse_unknown_timezone_setting() {
+        let settings = Settings::parse("timezone = \"Paris\"").unwrap();
+
+        assert!(settings.timezone().is_err());
+    }
+
+    #[test]
+    fn parse_unknown_month() {
+        let settings = Settings::parse(
+            r#"
+            [[periods]]
+            name = "Winter"
+            months = ["Janvier"]
+            "#,
+        );
+
+        assert!(settings.is_err());
+    }
+
+    #[test]
+    fn parse_unknown_setting() {
+        assert!(Settings::parse("colour = \"blue\"").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::calendar::Calendar;
+use crate::compare::{end_offset, start_offset};
+use crate::error::CalendarError;
+use crate::month::Month;
+use crate::week::first_day_year;
+use crate::year::{supported_years, BudgetYear};
+
+/// How many years before and after the given year we look at by default.
+const DEFAULT_WINDOW: i32 = 100;
+
+/// The budget months of a year only depend on the weekday of January 1st
+/// and on whether the year is a leap year, so there are only 14 kinds of years.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct YearKind {
+    first_day: Weekday,
+    leap: bool,
+}
+
+impl YearKind {
+    fn of(year: i32) -> YearKind {
+        YearKind {
+            first_day: first_day_year(year).weekday(),
+            leap: NaiveDate::from_ymd_opt(year, 2, 29).is_some(),
+        }
+    }
+}
+
+/// What we compare between two years: the number of weeks of each month and,
+/// when asked, how many days each month starts and ends away from its calendar month.
+#[derive(Debug, PartialEq)]
+struct Layout {
+    weeks: Vec<usize>,
+    offsets: Option<Vec<(i64, i64)>>,
+}
+
+impl Layout {
+    fn of(year: &BudgetYear, with_offsets: bool, calendar: &Calendar) -> Layout {
+        let months: &[Month] = year.months();
+
+        let weeks = months.iter().map(|month| month.weeks.len()).collect();
+        let offsets = with_offsets.then(|| {
+            months
+                .iter()
+                .zip(calendar.periods().iter())
+                .map(|(month, period)| {
+                    (
+                        month.start_date().map_or(0, |date| {
+                            start_offset(date, year.year(), period.first_month())
+                        }),
+                        month
+                            .end_date()
+                            .map_or(0, |date| end_offset(date, year.year(), period.last_month())),
+                    )
+                })
+                .collect()
+        });
+
+        Layout { weeks, offsets }
+    }
+}
+
+pub(crate) fn default_range(year: i32) -> RangeInclusive<i32> {
+    let supported = supported_years();
+    let from = year.saturating_sub(DEFAULT_WINDOW).max(*supported.start());
+    let to = year.saturating_add(DEFAULT_WINDOW).min(*supported.end());
+
+    from..=to
+}
+
+/// Lists the years of `range` (other than `year`) sharing the budget layout of `year`.
+///
+/// Only one year of each kind is actually split, the others are matched on their kind,
+/// which keeps this cheap even over the whole range supported by chrono.
+pub(crate) fn similar_years(
+    year: i32,
+    range: RangeInclusive<i32>,
+    with_offsets: bool,
+    calendar: &Calendar,
+) -> Result<Vec<i32>, CalendarError> {
+    for bound in [*range.start(), *range.end()] {
+        if !supported_years().contains(&bound) {
+            return Err(CalendarError::YearOutOfRange(bound));
+        }
+    }
+
+    let reference: Layout = Layout::of(&calendar.year(year)?, with_offsets, calendar);
+    let mut matching_kinds: HashMap<YearKind, bool> = HashMap::new();
+
+    let years = range
+        .filter(|candidate| *candidate != year)
+        .filter(|candidate| {
+            let kind = YearKind::of(*candidate);
+            *matching_kinds.entry(kind).or_insert_with(|| {
+                let representative = calendar.year(representative(kind)).unwrap();
+                Layout::of(&representative, with_offsets, calendar) == reference
+            })
+        })
+        .collect();
+
+    Ok(years)
+}
+
+/// A year of the given kind close to today.
+fn representative(kind: YearKind) -> i32 {
+    // The Gregorian calendar goes through the 14 kinds of years in 28 years
+    // (as long as we don't cross a century that isn't a leap year).
+    (2000..2028)
+        .find(|year| YearKind::of(*year) == kind)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn year_kind_2024() {
+        let kind = YearKind::of(2024);
+
+        assert_eq!(kind.first_day, Weekday::Mon);
+        assert!(kind.leap);
+    }
+
+    #[test]
+    fn representative_of_every_kind() {
+        for year in 1900..2100 {
+            let kind = YearKind::of(year);
+            assert_eq!(YearKind::of(representative(kind)), kind);
+        }
+    }
+
+    #[test]
+    fn similar_years_2026() {
+        let years = similar_years(2026, 2000..=2050, true, &Calendar::default()).unwrap();
+
+        // Common years starting on a Thursday.
+        assert_eq!(years, vec![2009, 2015, 2037, 2043]);
+    }
+
+    #[test]
+    fn similar_years_without_offsets() {
+        let with_offsets = similar_years(2026, 2000..=2050, true, &Calendar::default()).unwrap();
+        let without_offsets =
+            similar_years(2026, 2000..=2050, false, &Calendar::default()).unwrap();
+
+        assert!(without_offsets.len() >= with_offsets.len());
+        assert!(with_offsets
+            .iter()
+            .all(|year| without_offsets.contains(year)));
+        for year in without_offsets {
+            assert_eq!(
+                Layout::of(&BudgetYear::new(year).unwrap(), false, &Calendar::default()),
+                Layout::of(&BudgetYear::new(2026).unwrap(), false, &Calendar::default())
+            );
+        }
+    }
+
+    #[test]
+    fn similar_years_whole_range() {
+        let years = similar_years(2026, supported_years(), false, &Calendar::default()).unwrap();
+
+        assert!(years.contains(&2015));
+        assert!(years.len() > 10_000);
+    }
+
+    #[test]
+    fn similar_years_out_of_range() {
+        assert!(similar_years(2026, 0..=i32::MAX, false, &Calendar::default()).is_err());
+    }
+
+    #[test]
+    fn default_range_is_clamped() {
+        let end = *supported_years().end();
+
+        assert_eq!(default_range(2026), 1926..=2126);
+        assert_eq!(*default_range(end).end(), end);
+    }
+}
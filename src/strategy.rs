@@ -0,0 +1,265 @@
+use core::fmt;
+use core::str::FromStr;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use chrono::{Datelike, Month as ChronoMonth, Weekday};
+
+use crate::calendar::Calendar;
+use crate::month::{split_in_months, Month};
+use crate::week::{first_day_year, Week};
+
+/// Weeks in a quarter of the 4-4-5 calendars.
+const WEEKS_IN_QUARTER: usize = 13;
+
+/// How the weeks of a year are grouped into budget months.
+///
+/// Implement it to try another algorithm, and split years with
+/// [`Calendar::year_with`](crate::Calendar::year_with):
+///
+/// ```
+/// use budget_planner::{Calendar, Month, PeriodStrategy, Week};
+///
+/// /// The whole year in a single budget month.
+/// struct Yearly;
+///
+/// impl PeriodStrategy for Yearly {
+///     fn split(&self, weeks: &[Week], _: &Calendar) -> Vec<Month> {
+///         vec![Month::new("Year", weeks.to_vec())]
+///     }
+/// }
+///
+/// let year = Calendar::default().year_with(2024, &Yearly).unwrap();
+///
+/// assert_eq!(year.months()[0].weeks().len(), 53);
+/// ```
+pub trait PeriodStrategy {
+    /// Groups `weeks`, every week of a year from January 1st to December 31st, into
+    /// budget months named after the periods of `calendar` or anything else.
+    fn split(&self, weeks: &[Week], calendar: &Calendar) -> Vec<Month>;
+}
+
+/// The strategies shipped with the crate, and selected by name on the command line.
+///
+/// Each of them gives every week to a calendar month, and the weeks of the calendar
+/// months of a budget period go to that period.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Strategy {
+    /// A week goes to the calendar month given by the ownership rule.
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "monthly"))]
+    Monthly,
+    /// Quarters of 13 weeks, split in months of 4, 4 and 5 weeks.
+    #[cfg_attr(feature = "serde", serde(rename = "4-4-5"))]
+    FourFourFive,
+    /// Quarters of 13 weeks, split in months of 4, 5 and 4 weeks.
+    #[cfg_attr(feature = "serde", serde(rename = "4-5-4"))]
+    FourFiveFour,
+    /// Quarters of 13 weeks, split in months of 5, 4 and 4 weeks.
+    #[cfg_attr(feature = "serde", serde(rename = "5-4-4"))]
+    FiveFourFour,
+}
+
+impl Strategy {
+    /// The calendar month `week` is given to, with the options of `calendar`.
+    ///
+    /// The quarters of 13 weeks count the short first week of the year, and the weeks
+    /// past the 52nd go to December.
+    pub fn month_of(&self, week: &Week, calendar: &Calendar) -> ChronoMonth {
+        let pattern: [usize; 3] = match self {
+            Strategy::Monthly => return calendar.ownership().month_of(week),
+            Strategy::FourFourFive => [4, 4, 5],
+            Strategy::FourFiveFour => [4, 5, 4],
+            Strategy::FiveFourFour => [5, 4, 4],
+        };
+
+        let index: usize = week_index(week, calendar.week_start());
+        let (quarter, mut position) = (index / WEEKS_IN_QUARTER, index % WEEKS_IN_QUARTER);
+        if quarter > 3 {
+            return ChronoMonth::December;
+        }
+
+        let mut month: usize = quarter * 3;
+        for weeks in &pattern[..2] {
+            if position < *weeks {
+                break;
+            }
+            position -= weeks;
+            month += 1;
+        }
+
+        // Between 0 and 11, from January.
+        ChronoMonth::try_from(month as u8 + 1).unwrap()
+    }
+}
+
+/// The position of `week` in its year, from 0 for the week of January 1st.
+fn week_index(week: &Week, week_start: Weekday) -> usize {
+    let first_day = first_day_year(week.start_date.year());
+    // The days of the first week of the year before January 1st.
+    let shift: i64 = first_day.weekday().days_since(week_start).into();
+
+    ((week.start_date - first_day).num_days() + shift) as usize / 7
+}
+
+impl PeriodStrategy for Strategy {
+    fn split(&self, weeks: &[Week], calendar: &Calendar) -> Vec<Month> {
+        split_in_months(weeks, calendar, *self)
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Strategy::Monthly => write!(f, "monthly"),
+            Strategy::FourFourFive => write!(f, "4-4-5"),
+            Strategy::FourFiveFour => write!(f, "4-5-4"),
+            Strategy::FiveFourFour => write!(f, "5-4-4"),
+        }
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "monthly" => Ok(Strategy::Monthly),
+            "4-4-5" => Ok(Strategy::FourFourFive),
+            "4-5-4" => Ok(Strategy::FourFiveFour),
+            "5-4-4" => Ok(Strategy::FiveFourFour),
+            _ => Err(format!(
+                "unknown strategy {}, expected monthly, 4-4-5, 4-5-4 or 5-4-4",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+    use chrono::Month::*;
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::calendar::CalendarBuilder;
+    use crate::periods::Period;
+
+    fn week_counts(strategy: Strategy, year: i32) -> Vec<usize> {
+        CalendarBuilder::new()
+            .strategy(strategy)
+            .build_year(year)
+            .unwrap()
+            .months()
+            .iter()
+            .map(|month| month.weeks().len())
+            .collect()
+    }
+
+    #[test]
+    fn monthly_is_the_default() {
+        assert_eq!(
+            Calendar::default().year(2024).unwrap(),
+            CalendarBuilder::new()
+                .strategy(Strategy::Monthly)
+                .build_year(2024)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn four_four_five() {
+        // 2024 starts on a Monday, its 53rd week is Dec 30-31.
+        assert_eq!(
+            week_counts(Strategy::FourFourFive, 2024),
+            vec![4, 4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 6]
+        );
+        assert_eq!(
+            week_counts(Strategy::FourFiveFour, 2024),
+            vec![4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 5, 5]
+        );
+        assert_eq!(
+            week_counts(Strategy::FiveFourFour, 2024),
+            vec![5, 4, 4, 5, 4, 4, 5, 4, 4, 5, 4, 5]
+        );
+    }
+
+    #[test]
+    fn four_four_five_with_a_short_first_week() {
+        // 2023 starts on a Sunday: Jan 1 is a week of its own.
+        let year = CalendarBuilder::new()
+            .strategy(Strategy::FourFourFive)
+            .build_year(2023)
+            .unwrap();
+        let january = &year.months()[0];
+
+        assert_eq!(january.weeks().len(), 4);
+        assert_eq!(january.end_date(), NaiveDate::from_ymd_opt(2023, 1, 22));
+        assert_eq!(
+            year.months()[11].end_date(),
+            NaiveDate::from_ymd_opt(2023, 12, 31)
+        );
+    }
+
+    #[test]
+    fn four_four_five_with_periods() {
+        let year = CalendarBuilder::new()
+            .strategy(Strategy::FourFourFive)
+            .periods(vec![
+                Period::new("Q1", vec![January, February, March]),
+                Period::new("Q2", vec![April, May, June]),
+            ])
+            .build_year(2024)
+            .unwrap();
+
+        assert_eq!(year.months().len(), 2);
+        assert!(year.months().iter().all(|month| month.weeks().len() == 13));
+    }
+
+    #[test]
+    fn week_index_of_weeks() {
+        let year = CalendarBuilder::new()
+            .week_start(Weekday::Sun)
+            .build_year(2024)
+            .unwrap();
+
+        for (index, week) in year.weeks().iter().enumerate() {
+            assert_eq!(week_index(week, Weekday::Sun), index);
+        }
+    }
+
+    #[test]
+    fn custom_strategy() {
+        struct Halves;
+
+        impl PeriodStrategy for Halves {
+            fn split(&self, weeks: &[Week], _: &Calendar) -> Vec<Month> {
+                let (first, second) = weeks.split_at(weeks.len() / 2);
+
+                vec![
+                    Month::new("First", first.to_vec()),
+                    Month::new("Second", second.to_vec()),
+                ]
+            }
+        }
+
+        let year = Calendar::default().year_with(2024, &Halves).unwrap();
+
+        assert_eq!(year.months().len(), 2);
+        assert_eq!(year.months()[1].weeks().len(), 27);
+        assert_eq!(year.weeks().len(), 53);
+    }
+
+    #[test]
+    fn parse_strategy() {
+        assert_eq!("4-4-5".parse(), Ok(Strategy::FourFourFive));
+        assert_eq!("monthly".parse(), Ok(Strategy::Monthly));
+        assert!("4-4-4".parse::<Strategy>().is_err());
+        assert_eq!(Strategy::FiveFourFour.to_string(), "5-4-4");
+    }
+}
@@ -0,0 +1,473 @@
+use core::fmt;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use chrono::{Datelike, Month as ChronoMonth, NaiveDate};
+
+use crate::calendar::Calendar;
+use crate::month::Month;
+use crate::periods::Period;
+use crate::strategy::Strategy;
+use crate::week::{first_day_year, last_day_year, Week};
+
+/// The weeks a budget month should have, for each of its calendar months.
+const MIN_WEEKS_IN_MONTH: usize = 4;
+const MAX_WEEKS_IN_MONTH: usize = 5;
+
+/// A rule broken by a budget plan, see [`validate`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Violation {
+    /// The name of the budget month, `None` when the whole plan is concerned.
+    pub month: Option<String>,
+    /// The position of the week in its month, from 1.
+    pub week: Option<usize>,
+    pub kind: ViolationKind,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ViolationKind {
+    /// The plan has no week at all.
+    Empty,
+    /// A budget month has no week.
+    NoWeek,
+    /// A week ends before it starts, lasts more than 7 days or overlaps two years.
+    InvalidWeek(Week),
+    /// Days between two weeks aren't part of any week.
+    Gap { from: NaiveDate, to: NaiveDate },
+    /// A week starts before the end of the previous one.
+    Overlap {
+        previous_end: NaiveDate,
+        start: NaiveDate,
+    },
+    /// The first week doesn't start on January 1st.
+    StartOfYear(NaiveDate),
+    /// The last week doesn't end on December 31st.
+    EndOfYear(NaiveDate),
+    /// A budget month has too few or too many weeks.
+    WeekCount {
+        weeks: usize,
+        min: usize,
+        max: usize,
+    },
+    /// The name of a budget month isn't one of the budget periods.
+    UnknownMonth,
+    /// The strategy or ownership rule gives the week to another budget month, or to none.
+    Ownership { expected: Option<String> },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.month, self.week) {
+            (Some(month), Some(week)) => write!(f, "{}, week {}: {}", month, week, self.kind),
+            (Some(month), None) => write!(f, "{}: {}", month, self.kind),
+            _ => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationKind::Empty => write!(f, "the plan has no week"),
+            ViolationKind::NoWeek => write!(f, "the month has no week"),
+            ViolationKind::InvalidWeek(week) => write!(
+                f,
+                "the week from {} to {} must last 1 to 7 days in a single year",
+                week.start_date, week.end_date
+            ),
+            ViolationKind::Gap { from, to } => {
+                write!(f, "the days from {} to {} are in no week", from, to)
+            }
+            ViolationKind::Overlap {
+                previous_end,
+                start,
+            } => write!(
+                f,
+                "the week starts on {} but the previous one ends on {}",
+                start, previous_end
+            ),
+            ViolationKind::StartOfYear(date) => {
+                write!(f, "the plan starts on {} instead of January 1st", date)
+            }
+            ViolationKind::EndOfYear(date) => {
+                write!(f, "the plan ends on {} instead of December 31st", date)
+            }
+            ViolationKind::WeekCount { weeks, min, max } => write!(
+                f,
+                "the month has {} weeks, expected {} to {}",
+                weeks, min, max
+            ),
+            ViolationKind::UnknownMonth => write!(f, "the month isn't a budget period"),
+            ViolationKind::Ownership {
+                expected: Some(expected),
+            } => write!(f, "the week belongs to {}", expected),
+            ViolationKind::Ownership { expected: None } => {
+                write!(f, "the week belongs to no budget period")
+            }
+        }
+    }
+}
+
+/// Checks a budget plan, like one edited by hand, against `calendar`.
+///
+/// The weeks must follow each other without gap or overlap over a single year, each
+/// budget month must have 4 to 5 weeks per calendar month (and December one more with the
+/// 13 weeks quarters), and each week must be in the month the strategy and ownership rule of
+/// `calendar` give it to. Every violation is reported, in order.
+pub fn validate(months: &[Month], calendar: &Calendar) -> Vec<Violation> {
+    let mut violations: Vec<Violation> = Vec::new();
+    let owners: [Option<usize>; 12] = calendar.periods().owners();
+    let periods: Vec<&Period> = calendar.periods().iter().collect();
+
+    let mut previous: Option<Week> = None;
+
+    for month in months {
+        let violation = |week: Option<usize>, kind: ViolationKind| Violation {
+            month: Some(month.name.clone()),
+            week,
+            kind,
+        };
+        let period: Option<&Period> = periods
+            .iter()
+            .copied()
+            .find(|period| period.name == month.name);
+
+        match period {
+            Some(period) => {
+                // The 13 weeks quarters leave a 53rd week, given to December.
+                let extra_week: bool = calendar.strategy() != Strategy::Monthly
+                    && period.months.contains(&ChronoMonth::December);
+                let (min, max) = (
+                    period.months.len() * MIN_WEEKS_IN_MONTH,
+                    period.months.len() * MAX_WEEKS_IN_MONTH + usize::from(extra_week),
+                );
+                // The short weeks at the start and end of the year only count towards the minimum.
+                let full_weeks: usize = month.weeks.iter().filter(|week| week.days() == 7).count();
+
+                if month.weeks.is_empty() {
+                    violations.push(violation(None, ViolationKind::NoWeek));
+                } else if month.weeks.len() < min || full_weeks > max {
+                    let weeks = month.weeks.len();
+                    violations.push(violation(
+                        None,
+                        ViolationKind::WeekCount { weeks, min, max },
+                    ));
+                }
+            }
+            None => violations.push(violation(None, ViolationKind::UnknownMonth)),
+        }
+
+        for (index, week) in month.weeks.iter().enumerate() {
+            let position = Some(index + 1);
+
+            if let Some(previous) = previous {
+                let expected_start: Option<NaiveDate> = previous.end_date.succ_opt();
+
+                if week.start_date <= previous.end_date {
+                    violations.push(violation(
+                        position,
+                        ViolationKind::Overlap {
+                            previous_end: previous.end_date,
+                            start: week.start_date,
+                        },
+                    ));
+                } else if Some(week.start_date) != expected_start {
+                    violations.push(violation(
+                        position,
+                        ViolationKind::Gap {
+                            from: expected_start.unwrap(),
+                            to: week.start_date.pred_opt().unwrap(),
+                        },
+                    ));
+                }
+            }
+            previous = Some(*week);
+
+            if !is_valid_week(week) {
+                violations.push(violation(position, ViolationKind::InvalidWeek(*week)));
+                continue;
+            }
+
+            let owner: Option<&Period> = owners[calendar
+                .strategy()
+                .month_of(week, calendar)
+                .number_from_month() as usize
+                - 1]
+            .map(|index| periods[index]);
+
+            if period.is_some() && owner.map(|owner| &owner.name) != Some(&month.name) {
+                violations.push(violation(
+                    position,
+                    ViolationKind::Ownership {
+                        expected: owner.map(|owner| owner.name.clone()),
+                    },
+                ));
+            }
+        }
+    }
+
+    let mut weeks = months.iter().flat_map(|month| month.weeks.iter());
+    let Some(first_week) = weeks.next() else {
+        violations.insert(0, plan_violation(ViolationKind::Empty));
+        return violations;
+    };
+    let last_week = weeks.last().unwrap_or(first_week);
+
+    let year: i32 = first_week.start_date.year();
+    if first_week.start_date != first_day_year(year) {
+        violations.push(plan_violation(ViolationKind::StartOfYear(
+            first_week.start_date,
+        )));
+    }
+    if last_week.end_date != last_day_year(year) {
+        violations.push(plan_violation(ViolationKind::EndOfYear(last_week.end_date)));
+    }
+
+    violations
+}
+
+fn plan_violation(kind: ViolationKind) -> Violation {
+    Violation {
+        month: None,
+        week: None,
+        kind,
+    }
+}
+
+fn is_valid_week(week: &Week) -> bool {
+    (1..=7).contains(&week.days()) && week.start_date.year() == week.end_date.year()
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+    use chrono::Month::*;
+    use chrono::Weekday;
+
+    use super::*;
+    use crate::calendar::CalendarBuilder;
+    use crate::ownership::Ownership;
+
+    fn plan(year: i32, calendar: &Calendar) -> Vec<Month> {
+        calendar.year(year).unwrap().into_months()
+    }
+
+    fn kinds(violations: &[Violation]) -> Vec<&ViolationKind> {
+        violations.iter().map(|violation| &violation.kind).collect()
+    }
+
+    #[test]
+    fn generated_plans_are_valid() {
+        for ownership in [Ownership::Majority, Ownership::FirstDay, Ownership::LastDay] {
+            for week_start in [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ] {
+                let calendar = CalendarBuilder::new()
+                    .ownership(ownership)
+                    .week_start(week_start)
+                    .build()
+                    .unwrap();
+
+                // The 14 kinds of years.
+                for year in 2000..2028 {
+                    assert_eq!(validate(&plan(year, &calendar), &calendar), vec![]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plans_of_every_strategy_are_valid() {
+        for strategy in [
+            Strategy::FourFourFive,
+            Strategy::FourFiveFour,
+            Strategy::FiveFourFour,
+        ] {
+            for week_start in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+                let calendar = CalendarBuilder::new()
+                    .strategy(strategy)
+                    .week_start(week_start)
+                    .build()
+                    .unwrap();
+
+                for year in 2000..2028 {
+                    assert_eq!(
+                        validate(&plan(year, &calendar), &calendar),
+                        vec![],
+                        "{} {} {}",
+                        strategy,
+                        week_start,
+                        year
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn custom_periods_are_valid() {
+        let calendar = CalendarBuilder::new()
+            .periods(vec![
+                Period::new("Winter", vec![January, February, March]),
+                Period::new("Rest", vec![April, May, June, July, August, September]),
+                Period::new("Autumn", vec![October, November, December]),
+            ])
+            .build()
+            .unwrap();
+
+        assert_eq!(validate(&plan(2024, &calendar), &calendar), vec![]);
+    }
+
+    #[test]
+    fn week_moved_to_another_month() {
+        let calendar = Calendar::default();
+        let mut months = plan(2024, &calendar);
+
+        // The week from Jan 29 to Feb 04 goes back to January.
+        let week = months[1].weeks.remove(0);
+        months[0].weeks.push(week);
+
+        let violations = validate(&months, &calendar);
+
+        assert_eq!(
+            violations,
+            vec![Violation {
+                month: Some(String::from("January")),
+                week: Some(5),
+                kind: ViolationKind::Ownership {
+                    expected: Some(String::from("February"))
+                },
+            }]
+        );
+        assert_eq!(
+            violations[0].to_string(),
+            "January, week 5: the week belongs to February"
+        );
+    }
+
+    #[test]
+    fn too_many_weeks() {
+        let calendar = Calendar::default();
+        let mut months = plan(2024, &calendar);
+
+        let weeks: Vec<Week> = months[1].weeks.drain(..).collect();
+        months[0].weeks.extend(weeks);
+
+        let violations = validate(&months, &calendar);
+
+        assert_eq!(
+            violations[0],
+            Violation {
+                month: Some(String::from("January")),
+                week: None,
+                kind: ViolationKind::WeekCount {
+                    weeks: 9,
+                    min: 4,
+                    max: 5
+                },
+            }
+        );
+        // The 5 weeks of February, and February itself.
+        assert_eq!(violations.len(), 7);
+        assert_eq!(violations[6].to_string(), "February: the month has no week");
+    }
+
+    #[test]
+    fn gap_and_overlap() {
+        let calendar = Calendar::default();
+        let mut months = plan(2024, &calendar);
+
+        months[2].weeks[1].start_date = NaiveDate::from_ymd_opt(2024, 3, 12).unwrap();
+        months[5].weeks[2].start_date = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+
+        let violations = validate(&months, &calendar);
+
+        assert_eq!(
+            kinds(&violations),
+            vec![
+                &ViolationKind::Gap {
+                    from: NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(),
+                    to: NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(),
+                },
+                &ViolationKind::Overlap {
+                    previous_end: NaiveDate::from_ymd_opt(2024, 6, 16).unwrap(),
+                    start: NaiveDate::from_ymd_opt(2024, 6, 15).unwrap(),
+                },
+                &ViolationKind::InvalidWeek(months[5].weeks[2]),
+            ]
+        );
+        assert_eq!(
+            violations[1].to_string(),
+            "June, week 3: the week starts on 2024-06-15 but the previous one ends on 2024-06-16"
+        );
+    }
+
+    #[test]
+    fn partial_year() {
+        let calendar = Calendar::default();
+        let mut months = plan(2024, &calendar);
+
+        months.remove(11);
+        months[0].weeks.remove(0);
+
+        let violations = validate(&months, &calendar);
+
+        assert_eq!(
+            kinds(&violations),
+            vec![
+                &ViolationKind::WeekCount {
+                    weeks: 3,
+                    min: 4,
+                    max: 5
+                },
+                &ViolationKind::StartOfYear(NaiveDate::from_ymd_opt(2024, 1, 8).unwrap()),
+                &ViolationKind::EndOfYear(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_and_empty_months() {
+        let calendar = Calendar::default();
+        let mut months = plan(2024, &calendar);
+
+        months[0].name = String::from("Janvier");
+        months.push(Month {
+            name: String::from("December"),
+            weeks: vec![],
+        });
+
+        let violations = validate(&months, &calendar);
+
+        assert_eq!(
+            violations,
+            vec![
+                Violation {
+                    month: Some(String::from("Janvier")),
+                    week: None,
+                    kind: ViolationKind::UnknownMonth,
+                },
+                Violation {
+                    month: Some(String::from("December")),
+                    week: None,
+                    kind: ViolationKind::NoWeek,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_plan() {
+        assert_eq!(
+            kinds(&validate(&[], &Calendar::default())),
+            vec![&ViolationKind::Empty]
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! The JavaScript API, built with `--features wasm` for `wasm32-unknown-unknown`.
+//!
+//! Options and results go through JSON strings, with the same shapes as the command line:
+//! the options are the ones of a [`CalendarBuilder`], the budget months the output of
+//! `budget_planner --year`.
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::calendar::{Calendar, CalendarBuilder};
+use crate::id::{self, IdentifiedMonth, IdentifiedMonths};
+use crate::month::Month;
+
+/// A budget month with its id and the calendar year it belongs to.
+#[derive(Serialize)]
+struct YearMonth<'a> {
+    year: i32,
+    #[serde(flatten)]
+    month: IdentifiedMonth<'a>,
+}
+
+/// The budget months of `year`, as a JSON array.
+///
+/// `options` is a JSON object like `{"week_start": "Sun", "ownership": "first-day"}`.
+#[wasm_bindgen(js_name = budgetMonths)]
+pub fn budget_months(year: i32, options: Option<String>) -> Result<String, JsError> {
+    months_json(year, options.as_deref()).map_err(|e| JsError::new(&e))
+}
+
+/// The budget months from `from` to `to` included, as a JSON array of months with their `year`
+/// and ids, numbered again from 1 every year.
+#[wasm_bindgen(js_name = budgetMonthsBetween)]
+pub fn budget_months_between(
+    from: i32,
+    to: i32,
+    options: Option<String>,
+) -> Result<String, JsError> {
+    months_between_json(from, to, options.as_deref()).map_err(|e| JsError::new(&e))
+}
+
+fn calendar(options: Option<&str>) -> Result<Calendar, String> {
+    let builder: CalendarBuilder = match options {
+        Some(options) => {
+            serde_json::from_str(options).map_err(|e| format!("invalid options: {}", e))?
+        }
+        None => CalendarBuilder::new(),
+    };
+
+    builder.build().map_err(|e| e.to_string())
+}
+
+fn months_json(year: i32, options: Option<&str>) -> Result<String, String> {
+    let months: Vec<Month> = calendar(options)?
+        .year(year)
+        .map_err(|e| e.to_string())?
+        .into_months();
+
+    serde_json::to_string(&IdentifiedMonths(&months)).map_err(|e| e.to_string())
+}
+
+fn months_between_json(from: i32, to: i32, options: Option<&str>) -> Result<String, String> {
+    let (years, months): (Vec<i32>, Vec<Month>) = calendar(options)?
+        .months(from..=to)
+        .map_err(|e| e.to_string())?
+        .unzip();
+    let months: Vec<YearMonth> = years
+        .into_iter()
+        .zip(id::month_ids(&months, from))
+        .zip(&months)
+        .map(|((year, id), month)| YearMonth {
+            year,
+            month: IdentifiedMonth::new(id, month),
+        })
+        .collect();
+
+    serde_json::to_string(&months).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn months_of_a_year() {
+        let json = months_json(2024, None).unwrap();
+
+        assert!(json.starts_with(
+            "[{\"id\":\"2024-M01\",\"name\":\"January\",\"weeks\":[{\"id\":\"2024-M01-W1\",\"start_date\":\"2024-01-01\",\"end_date\":\"2024-01-07\",\"start_day\":\"Mon\"}"
+        ));
+    }
+
+    #[test]
+    fn months_with_options() {
+        let options = r#"{"week_start": "Sun", "periods": [{"name": "Winter", "months": ["January", "February"]}]}"#;
+
+        let json = months_json(2024, Some(options)).unwrap();
+
+        assert!(json.starts_with("[{\"id\":\"2024-M01\",\"name\":\"Winter\""));
+        assert!(json.contains("\"start_day\":\"Sun\""));
+    }
+
+    #[test]
+    fn months_between_years() {
+        let json = months_between_json(2023, 2024, None).unwrap();
+
+        assert_eq!(json.matches("\"name\":\"January\"").count(), 2);
+        assert!(json.contains("{\"year\":2024,\"id\":\"2024-M01\",\"name\":\"January\""));
+        assert!(json.contains("\"id\":\"2024-M12-W5\""));
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert!(months_json(2024, Some("{\"timezone\": \"Europe/Paris\"}"))
+            .unwrap_err()
+            .starts_with("invalid options"));
+        assert!(months_json(2024, Some("{\"periods\": []}")).is_err());
+        assert!(months_json(i32::MAX, None).is_err());
+    }
+}
@@ -0,0 +1,644 @@
+use core::iter::FusedIterator;
+use core::ops::RangeBounds;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use chrono::Month::*;
+use chrono::{Datelike, Duration, Month as ChronoMonth, NaiveDate, NaiveWeek, Weekday};
+
+use crate::civil::{CivilDate, CivilWeekday};
+use crate::error::CalendarError;
+use crate::year::year_bounds;
+
+/// A year has 53 or 54 weeks, counting the first and last ones.
+#[cfg(feature = "alloc")]
+const WEEKS_IN_YEAR: usize = 54;
+
+/// A budget week.
+///
+/// Weeks last 7 days from the configured week start, Monday by default, except the first
+/// and the last week of a year which are cut so that a week never overlaps two years.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(transform = crate::id::week_id_schema))]
+pub struct Week {
+    #[cfg_attr(feature = "schema", schemars(schema_with = "date_schema"))]
+    pub(crate) start_date: NaiveDate,
+    #[cfg_attr(feature = "schema", schemars(schema_with = "date_schema"))]
+    pub(crate) end_date: NaiveDate,
+    pub(crate) start_day: Weekday,
+}
+
+/// Dates are written by chrono as `YYYY-MM-DD`, with a sign and more digits for years
+/// outside of 0 to 9999, which the `date` format of JSON Schema doesn't allow.
+#[cfg(feature = "schema")]
+fn date_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": "string",
+        "pattern": "^([0-9]{4}|[+-][0-9]{4,})-[0-9]{2}-[0-9]{2}$"
+    })
+}
+
+impl Week {
+    /// The first day of the week.
+    pub fn start_date(&self) -> NaiveDate {
+        self.start_date
+    }
+
+    /// The last day of the week, included.
+    pub fn end_date(&self) -> NaiveDate {
+        self.end_date
+    }
+
+    /// The weekday of [`Week::start_date`].
+    pub fn start_day(&self) -> Weekday {
+        self.start_day
+    }
+
+    /// [`Week::start_date`] as another date type, `None` when out of its range.
+    pub fn start_date_as<D: CivilDate>(&self) -> Option<D> {
+        D::from_naive_date(self.start_date)
+    }
+
+    /// [`Week::end_date`] as another date type, `None` when out of its range.
+    pub fn end_date_as<D: CivilDate>(&self) -> Option<D> {
+        D::from_naive_date(self.end_date)
+    }
+
+    /// [`Week::start_day`] as another weekday type.
+    pub fn start_day_as<W: CivilWeekday>(&self) -> W {
+        W::from_weekday(self.start_day)
+    }
+
+    /// How many days the week lasts, between 1 and 7.
+    pub fn days(&self) -> i64 {
+        (self.end_date - self.start_date).num_days() + 1
+    }
+
+    /// Whether `date` is part of the week.
+    ///
+    /// `false` when `date` is out of the range of chrono.
+    pub fn contains(&self, date: impl CivilDate) -> bool {
+        let Some(date) = date.to_naive_date() else {
+            return false;
+        };
+
+        self.start_date <= date && date <= self.end_date
+    }
+}
+
+pub(crate) fn first_day_year(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, 1, 1).unwrap()
+}
+
+pub(crate) fn last_day_year(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, 12, 31).unwrap()
+}
+
+pub(crate) fn first_day_month(year: i32, month: ChronoMonth) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).unwrap()
+}
+
+pub(crate) fn last_day_month(year: i32, month: ChronoMonth) -> NaiveDate {
+    match month {
+        December => last_day_year(year),
+        _ => sub_day(first_day_month(year, month.succ()), 1),
+    }
+}
+
+pub(crate) fn add_day(date: NaiveDate, nbr: i64) -> NaiveDate {
+    date + Duration::days(nbr)
+}
+
+pub(crate) fn sub_day(date: NaiveDate, nbr: i64) -> NaiveDate {
+    date - Duration::days(nbr)
+}
+
+/// The first week of a year starts on January 1st, whatever the weekday,
+/// and ends the day before the next `week_start`.
+pub(crate) fn define_first_week(date: NaiveDate, week_start: Weekday) -> Week {
+    let week: NaiveWeek = date.week(week_start);
+
+    Week {
+        start_date: date,
+        start_day: date.weekday(),
+        end_date: week.last_day(),
+    }
+}
+
+/// We want to define the last week of a year based on the last day.
+/// We don't want to have the last week overlap on the next year
+pub(crate) fn define_last_week(date: NaiveDate, week_start: Weekday) -> Week {
+    let start_date: NaiveDate = date.week(week_start).first_day();
+
+    Week {
+        start_date,
+        start_day: start_date.weekday(),
+        end_date: date,
+    }
+}
+
+// Those week should always last 7 days, from `date`
+// Because we already covered the two special cases.
+pub(crate) fn define_week(date: NaiveDate) -> Week {
+    Week {
+        start_date: date,
+        end_date: add_day(date, 6),
+        start_day: date.weekday(),
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn define_weeks_in_year(
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    week_start: Weekday,
+) -> Vec<Week> {
+    let mut weeks: Vec<Week> = Vec::with_capacity(WEEKS_IN_YEAR);
+    weeks.extend(Weeks::new(start_date, end_date, week_start));
+
+    weeks
+}
+
+/// Lazily yields the budget weeks from a date to another, included.
+///
+/// A new year always starts a new week, as in [`Week`]. See [`Weeks::of_years`],
+/// or `Calendar::weeks` to use the week start of a calendar.
+#[derive(Debug, Clone)]
+pub struct Weeks {
+    next_date: Option<NaiveDate>,
+    end_date: NaiveDate,
+    week_start: Weekday,
+    // The last day of the year of `next_date`, or `end_date` when it comes first.
+    last_date: NaiveDate,
+}
+
+impl Weeks {
+    pub(crate) fn new(start_date: NaiveDate, end_date: NaiveDate, week_start: Weekday) -> Weeks {
+        Weeks {
+            next_date: Some(start_date),
+            end_date,
+            week_start,
+            last_date: last_day_year(start_date.year()).min(end_date),
+        }
+    }
+
+    /// The weeks of `years`, which can be open-ended like `2024..`.
+    ///
+    /// This doesn't allocate, so it is available without the `alloc` feature.
+    pub fn of_years(
+        years: impl RangeBounds<i32>,
+        week_start: impl CivilWeekday,
+    ) -> Result<Weeks, CalendarError> {
+        let (first_year, last_year) = year_bounds(years)?;
+
+        Ok(Weeks::new(
+            first_day_year(first_year),
+            last_day_year(last_year),
+            week_start.to_weekday(),
+        ))
+    }
+}
+
+impl Iterator for Weeks {
+    type Item = Week;
+
+    fn next(&mut self) -> Option<Week> {
+        let start_date: NaiveDate = self.next_date.filter(|date| *date <= self.end_date)?;
+        if start_date > self.last_date {
+            self.last_date = last_day_year(start_date.year()).min(self.end_date);
+        }
+        let last_date: NaiveDate = self.last_date;
+
+        let week: Week = if start_date.weekday() != self.week_start {
+            define_first_week(start_date, self.week_start)
+        } else if (last_date - start_date).num_days() >= 7 {
+            define_week(start_date)
+        } else {
+            define_last_week(last_date, self.week_start)
+        };
+
+        // Only useful when the range stops in the middle of a first week.
+        let end_date: NaiveDate = week.end_date.min(last_date);
+        self.next_date = end_date.succ_opt();
+
+        Some(Week { end_date, ..week })
+    }
+}
+
+impl FusedIterator for Weeks {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn first_day_year_2022() {
+        let first_day_2022: NaiveDate = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        assert_eq!(first_day_year(2022), first_day_2022);
+    }
+
+    #[test]
+    fn last_day_year_2022() {
+        let last_day_2022: NaiveDate = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+        assert_eq!(last_day_year(2022), last_day_2022);
+    }
+
+    #[test]
+    fn first_day_year_2023() {
+        let first_day_2023: NaiveDate = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        assert_eq!(first_day_year(2023), first_day_2023);
+    }
+
+    #[test]
+    fn last_day_year_2023() {
+        let last_day_2023: NaiveDate = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        assert_eq!(last_day_year(2023), last_day_2023);
+    }
+
+    #[test]
+    fn define_week_starting_with_monday() {
+        let start_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date: NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+        };
+
+        assert_eq!(define_first_week(start_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_starting_with_tuesday() {
+        let start_date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let week: Week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date: NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+        };
+
+        assert_eq!(define_first_week(start_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_starting_with_wednesday() {
+        let start_date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        let week: Week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date: NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+        };
+
+        assert_eq!(define_first_week(start_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_starting_with_thursday() {
+        let start_date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 4).unwrap();
+        let week: Week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date: NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+        };
+
+        assert_eq!(define_first_week(start_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_starting_with_friday() {
+        let start_date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let week: Week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date: NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(),
+        };
+
+        assert_eq!(define_first_week(start_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_starting_with_saturday() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date: NaiveDate::from_ymd_opt(2022, 1, 2).unwrap(),
+        };
+        assert_eq!(define_first_week(start_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_starting_with_sunday() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+        };
+
+        assert_eq!(define_first_week(start_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_ending_with_sunday() {
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 28).unwrap();
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        assert_eq!(define_last_week(end_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_ending_with_sartuday() {
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 27).unwrap();
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        assert_eq!(define_last_week(end_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_ending_with_friday() {
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 26).unwrap();
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        assert_eq!(define_last_week(end_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_ending_with_thursday() {
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 25).unwrap();
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        assert_eq!(define_last_week(end_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_ending_with_wednesday() {
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 24).unwrap();
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        assert_eq!(define_last_week(end_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_ending_with_tuesday() {
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 23).unwrap();
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        assert_eq!(define_last_week(end_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_week_ending_with_monday() {
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 22).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        assert_eq!(define_last_week(end_date, Weekday::Mon), week);
+    }
+
+    #[test]
+    fn define_normal_week() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2023, 5, 7).unwrap();
+
+        let week = Week {
+            start_date,
+            start_day: start_date.weekday(),
+            end_date,
+        };
+
+        let defined_week: Week = define_week(start_date);
+
+        assert_eq!(defined_week, week);
+        assert_eq!(defined_week.start_day, Weekday::Mon);
+    }
+
+    #[test]
+    fn define_range_week_special_start() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+
+        let year: Vec<Week> = Weeks::new(start_date, end_date, Weekday::Mon).collect();
+
+        assert_eq!(year.len(), 52);
+
+        let first_date: &NaiveDate = &year.first().unwrap().start_date;
+        let last_date: &NaiveDate = &year.last().unwrap().end_date;
+
+        assert_eq!(&start_date, first_date);
+        assert_eq!(&end_date, last_date);
+    }
+
+    #[test]
+    fn define_range_week_special_end() {
+        let start_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2024, 12, 29).unwrap();
+
+        let year: Vec<Week> = Weeks::new(start_date, end_date, Weekday::Mon).collect();
+
+        assert_eq!(year.len(), 52);
+
+        let first_date: &NaiveDate = &year.first().unwrap().start_date;
+        let last_date: &NaiveDate = &year.last().unwrap().end_date;
+
+        assert_eq!(&start_date, first_date);
+        assert_eq!(&end_date, last_date);
+    }
+
+    #[test]
+    fn define_range_week_special_start_and_end() {
+        let start_date = NaiveDate::from_ymd_opt(2022, 1, 3).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
+
+        let year: Vec<Week> = Weeks::new(start_date, end_date, Weekday::Mon).collect();
+
+        assert_eq!(year.len(), 51);
+
+        let first_date: &NaiveDate = &year.first().unwrap().start_date;
+        let last_date: &NaiveDate = &year.last().unwrap().end_date;
+
+        assert_eq!(&start_date, first_date);
+        assert_eq!(&end_date, last_date);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn define_weeks_year() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+
+        let year: Vec<Week> = define_weeks_in_year(start_date, end_date, Weekday::Mon);
+
+        assert_eq!(year.len(), 53);
+
+        let first_date: &NaiveDate = &year.first().unwrap().start_date;
+        let last_date: &NaiveDate = &year.last().unwrap().end_date;
+
+        assert_eq!(&start_date, first_date);
+        assert_eq!(&end_date, last_date);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn define_weeks_year_leap() {
+        let start_date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+
+        let year: Vec<Week> = define_weeks_in_year(start_date, end_date, Weekday::Mon);
+
+        assert_eq!(year.len(), 53);
+
+        let first_date: &NaiveDate = &year.first().unwrap().start_date;
+        let last_date: &NaiveDate = &year.last().unwrap().end_date;
+
+        assert_eq!(&start_date, first_date);
+        assert_eq!(&end_date, last_date);
+    }
+
+    #[test]
+    fn weeks_across_years() {
+        let start_date = NaiveDate::from_ymd_opt(2023, 12, 18).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
+
+        let weeks: Vec<Week> = Weeks::new(start_date, end_date, Weekday::Mon).collect();
+
+        // 2023-12-31 is a Sunday, the week of 2024-01-01 is a full one.
+        assert_eq!(weeks.len(), 4);
+        assert_eq!(
+            weeks[1].end_date,
+            NaiveDate::from_ymd_opt(2023, 12, 31).unwrap()
+        );
+        assert_eq!(
+            weeks[2].start_date,
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn weeks_cut_at_new_year() {
+        let start_date = NaiveDate::from_ymd_opt(2024, 12, 23).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2025, 1, 12).unwrap();
+
+        let weeks: Vec<Week> = Weeks::new(start_date, end_date, Weekday::Mon).collect();
+
+        assert_eq!(
+            weeks
+                .iter()
+                .map(|week| (week.start_date.day(), week.end_date.day()))
+                .collect::<Vec<(u32, u32)>>(),
+            vec![(23, 29), (30, 31), (1, 5), (6, 12)]
+        );
+    }
+
+    #[test]
+    fn weeks_stopping_in_first_week() {
+        let start_date = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let end_date = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+
+        let weeks: Vec<Week> = Weeks::new(start_date, end_date, Weekday::Mon).collect();
+
+        assert_eq!(weeks.len(), 1);
+        assert_eq!(weeks[0].end_date, end_date);
+    }
+
+    #[test]
+    fn weeks_until_the_last_supported_day() {
+        let start_date = NaiveDate::from_ymd_opt(NaiveDate::MAX.year(), 12, 1).unwrap();
+
+        let mut weeks = Weeks::new(start_date, NaiveDate::MAX, Weekday::Mon);
+
+        assert_eq!(weeks.by_ref().last().unwrap().end_date, NaiveDate::MAX);
+        assert_eq!(weeks.next(), None);
+    }
+
+    #[test]
+    fn weeks_of_years() {
+        let mut weeks = Weeks::of_years(2024..2026, Weekday::Mon).unwrap();
+
+        assert_eq!(
+            weeks.next().unwrap().start_date,
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+        );
+        assert_eq!(
+            weeks.last().unwrap().end_date,
+            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+        );
+        assert!(Weeks::of_years(i32::MIN.., Weekday::Mon).is_err());
+    }
+
+    #[test]
+    fn week_accessors() {
+        let week: Week =
+            define_first_week(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(), Weekday::Mon);
+
+        assert_eq!(
+            week.start_date(),
+            NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()
+        );
+        assert_eq!(
+            week.end_date(),
+            NaiveDate::from_ymd_opt(2022, 1, 2).unwrap()
+        );
+        assert_eq!(week.start_day(), Weekday::Sat);
+        assert_eq!(week.days(), 2);
+        assert!(week.contains(NaiveDate::from_ymd_opt(2022, 1, 2).unwrap()));
+        assert!(!week.contains(NaiveDate::from_ymd_opt(2022, 1, 3).unwrap()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn week_round_trip() {
+        let week = define_week(NaiveDate::from_ymd_opt(2024, 1, 29).unwrap());
+
+        let json = serde_json::to_string(&week).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"start_date":"2024-01-29","end_date":"2024-02-04","start_day":"Mon"}"#
+        );
+        assert_eq!(serde_json::from_str::<Week>(&json).unwrap(), week);
+    }
+}
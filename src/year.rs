@@ -0,0 +1,290 @@
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::CalendarError;
+
+#[cfg(feature = "alloc")]
+use crate::calendar::Calendar;
+#[cfg(feature = "alloc")]
+use crate::civil::CivilDate;
+#[cfg(feature = "alloc")]
+use crate::id::{MonthId, WeekId};
+#[cfg(feature = "alloc")]
+use crate::month::Month;
+#[cfg(feature = "alloc")]
+use crate::strategy::PeriodStrategy;
+#[cfg(feature = "alloc")]
+use crate::week::{define_weeks_in_year, first_day_year, last_day_year, Week};
+
+/// A year split into budget weeks and budget months.
+///
+/// ```
+/// use budget_planner::BudgetYear;
+/// use chrono::NaiveDate;
+///
+/// let year = BudgetYear::new(2024).unwrap();
+///
+/// assert_eq!(year.weeks().len(), 53);
+/// assert_eq!(year.months().len(), 12);
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 30).unwrap();
+/// assert_eq!(year.month_of(date).unwrap().name(), "February");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BudgetYear {
+    year: i32,
+    weeks: Vec<Week>,
+    months: Vec<Month>,
+}
+
+#[cfg(feature = "alloc")]
+impl BudgetYear {
+    /// Splits `year` with the default [`Calendar`]: one budget month per calendar month.
+    ///
+    /// Use a [`crate::CalendarBuilder`] to change how the year is split.
+    pub fn new(year: i32) -> Result<BudgetYear, CalendarError> {
+        Calendar::default().year(year)
+    }
+
+    pub(crate) fn with_calendar<S: PeriodStrategy + ?Sized>(
+        year: i32,
+        calendar: &Calendar,
+        strategy: &S,
+    ) -> Result<BudgetYear, CalendarError> {
+        if !supported_years().contains(&year) {
+            return Err(CalendarError::YearOutOfRange(year));
+        }
+
+        let weeks: Vec<Week> = define_weeks_in_year(
+            first_day_year(year),
+            last_day_year(year),
+            calendar.week_start(),
+        );
+        let months: Vec<Month> = strategy.split(&weeks, calendar);
+
+        Ok(BudgetYear {
+            year,
+            weeks,
+            months,
+        })
+    }
+
+    /// The calendar year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Every week of the year, from January 1st to December 31st.
+    pub fn weeks(&self) -> &[Week] {
+        &self.weeks
+    }
+
+    /// The budget months, in calendar order.
+    pub fn months(&self) -> &[Month] {
+        &self.months
+    }
+
+    /// Consumes the year, keeping its budget months.
+    pub fn into_months(self) -> Vec<Month> {
+        self.months
+    }
+
+    /// The week `date` belongs to, `None` when `date` is in another year or out of the range of
+    /// chrono.
+    pub fn week_of(&self, date: impl CivilDate) -> Option<&Week> {
+        let date: NaiveDate = date.to_naive_date()?;
+
+        self.weeks.iter().find(|week| week.contains(date))
+    }
+
+    /// The budget month `date` belongs to.
+    ///
+    /// `None` when `date` is in another year or out of the range of chrono, or in a week left out
+    /// of every budget period.
+    pub fn month_of(&self, date: impl CivilDate) -> Option<&Month> {
+        let date: NaiveDate = date.to_naive_date()?;
+
+        self.months.iter().find(|month| month.contains(date))
+    }
+
+    /// The id of the week `date` belongs to, `None` like [`BudgetYear::month_of`].
+    pub fn id_of(&self, date: impl CivilDate) -> Option<WeekId> {
+        let date: NaiveDate = date.to_naive_date()?;
+        let (month_index, month) = (1..)
+            .zip(&self.months)
+            .find(|(_, month)| month.contains(date))?;
+        let week_index: u32 = (1..)
+            .zip(month.weeks())
+            .find_map(|(index, week)| week.contains(date).then_some(index))?;
+
+        Some(MonthId::new(self.year, month_index).week(week_index))
+    }
+
+    /// The budget month identified by `id`, `None` when `id` is in another year or past the
+    /// last month.
+    pub fn month(&self, id: MonthId) -> Option<&Month> {
+        match id.year == self.year {
+            true => self
+                .months
+                .get(usize::try_from(id.month).ok()?.checked_sub(1)?),
+            false => None,
+        }
+    }
+
+    /// The week identified by `id`, `None` when its month or the week itself doesn't exist.
+    pub fn week(&self, id: WeekId) -> Option<&Week> {
+        let month: &Month = self.month(id.month)?;
+
+        month
+            .weeks()
+            .get(usize::try_from(id.week).ok()?.checked_sub(1)?)
+    }
+}
+
+/// The years we can split, the ones supported by chrono.
+pub(crate) fn supported_years() -> RangeInclusive<i32> {
+    NaiveDate::MIN.year()..=NaiveDate::MAX.year()
+}
+
+/// The first and last years of `years`, checking they are supported.
+///
+/// An unbounded side stops at the first or last supported year.
+pub(crate) fn year_bounds(years: impl RangeBounds<i32>) -> Result<(i32, i32), CalendarError> {
+    let supported = supported_years();
+
+    let first_year: i32 = match years.start_bound() {
+        Bound::Included(year) => *year,
+        Bound::Excluded(year) => year
+            .checked_add(1)
+            .ok_or(CalendarError::YearOutOfRange(*year))?,
+        Bound::Unbounded => *supported.start(),
+    };
+    let last_year: i32 = match years.end_bound() {
+        Bound::Included(year) => *year,
+        Bound::Excluded(year) => year
+            .checked_sub(1)
+            .ok_or(CalendarError::YearOutOfRange(*year))?,
+        Bound::Unbounded => *supported.end(),
+    };
+
+    for year in [first_year, last_year] {
+        if !supported.contains(&year) {
+            return Err(CalendarError::YearOutOfRange(year));
+        }
+    }
+
+    Ok((first_year, last_year))
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use chrono::Month::*;
+
+    use super::*;
+    use crate::calendar::CalendarBuilder;
+    use crate::periods::Period;
+
+    #[test]
+    fn budget_year_2023() {
+        let year = BudgetYear::new(2023).unwrap();
+
+        assert_eq!(year.year(), 2023);
+        assert_eq!(year.weeks().len(), 53);
+        assert_eq!(
+            year.months()
+                .iter()
+                .map(|month| month.weeks().len())
+                .collect::<Vec<usize>>(),
+            vec![5, 4, 5, 4, 4, 5, 4, 5, 4, 4, 5, 4]
+        );
+    }
+
+    #[test]
+    fn budget_year_out_of_range() {
+        assert_eq!(
+            BudgetYear::new(i32::MAX),
+            Err(CalendarError::YearOutOfRange(i32::MAX))
+        );
+    }
+
+    #[test]
+    fn budget_year_supported_bounds() {
+        let years = supported_years();
+
+        assert!(BudgetYear::new(*years.start()).is_ok());
+        assert!(BudgetYear::new(*years.end()).is_ok());
+    }
+
+    #[test]
+    fn year_bounds_of_ranges() {
+        let supported = supported_years();
+
+        assert_eq!(year_bounds(2020..2025), Ok((2020, 2024)));
+        assert_eq!(year_bounds(2020..), Ok((2020, *supported.end())));
+        assert_eq!(year_bounds(..=2020), Ok((*supported.start(), 2020)));
+        assert_eq!(
+            year_bounds(2020..=i32::MAX),
+            Err(CalendarError::YearOutOfRange(i32::MAX))
+        );
+    }
+
+    #[test]
+    fn lookup_week_and_month() {
+        let year = BudgetYear::new(2024).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 30).unwrap();
+
+        let week = year.week_of(date).unwrap();
+
+        assert_eq!(
+            week.start_date(),
+            NaiveDate::from_ymd_opt(2024, 1, 29).unwrap()
+        );
+        assert_eq!(
+            week.end_date(),
+            NaiveDate::from_ymd_opt(2024, 2, 4).unwrap()
+        );
+        assert_eq!(year.month_of(date).unwrap().name(), "February");
+    }
+
+    #[test]
+    fn lookup_by_id() {
+        let year = BudgetYear::new(2024).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 2, 14).unwrap();
+
+        let id: WeekId = year.id_of(date).unwrap();
+
+        assert_eq!(id, WeekId::new(2024, 2, 3));
+        assert_eq!(year.week(id), year.week_of(date));
+        assert_eq!(year.month(id.month).unwrap().name(), "February");
+        assert_eq!(year.month(MonthId::new(2024, 13)), None);
+        assert_eq!(year.month(MonthId::new(2023, 2)), None);
+        assert_eq!(year.week(WeekId::new(2024, 2, 6)), None);
+        assert_eq!(year.week(WeekId::new(2024, 0, 1)), None);
+    }
+
+    #[test]
+    fn lookup_in_another_year() {
+        let year = BudgetYear::new(2024).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+        assert_eq!(year.week_of(date), None);
+        assert_eq!(year.month_of(date), None);
+    }
+
+    #[test]
+    fn lookup_outside_periods() {
+        let year = CalendarBuilder::new()
+            .periods(vec![Period::from(January)])
+            .build_year(2024)
+            .unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 7, 14).unwrap();
+
+        assert!(year.week_of(date).is_some());
+        assert_eq!(year.month_of(date), None);
+    }
+}
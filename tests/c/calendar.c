@@ -0,0 +1,47 @@
+/* Splits 2024 through the C API, compiled and run by tests/ffi.rs. */
+#include <stdio.h>
+#include <string.h>
+
+#include "budget_planner.h"
+
+#define CHECK(condition)                                            \
+    if (!(condition)) {                                             \
+        fprintf(stderr, "line %d: %s failed\n", __LINE__, #condition); \
+        return 1;                                                   \
+    }
+
+int main(void) {
+    BpCalendar *calendar = NULL;
+    CHECK(bp_calendar_new(2024, NULL, &calendar) == BP_STATUS_OK);
+    CHECK(bp_calendar_month_count(calendar) == 12);
+    CHECK(strcmp(bp_calendar_month_name(calendar, 1), "February") == 0);
+    CHECK(bp_calendar_week_count(calendar, 1) == 5);
+
+    BpWeek week;
+    CHECK(bp_calendar_week(calendar, 1, 0, &week) == BP_STATUS_OK);
+    CHECK(week.start.year == 2024 && week.start.month == 1 && week.start.day == 29);
+    CHECK(week.end.year == 2024 && week.end.month == 2 && week.end.day == 4);
+    CHECK(bp_calendar_week(calendar, 1, 5, &week) == BP_STATUS_INDEX_OUT_OF_RANGE);
+
+    size_t month = 0, index = 0;
+    BpDate date = {2024, 1, 30};
+    CHECK(bp_calendar_lookup(calendar, date, &month, &index) == BP_STATUS_OK);
+    CHECK(month == 1 && index == 0);
+
+    for (size_t m = 0; m < bp_calendar_month_count(calendar); m++) {
+        printf("%s: %zu weeks\n", bp_calendar_month_name(calendar, m), bp_calendar_week_count(calendar, m));
+    }
+    bp_calendar_free(calendar);
+
+    BpCalendar *winter = NULL;
+    const char *options = "{\"periods\": [{\"name\": \"Winter\", \"months\": [\"January\", \"February\"]}]}";
+    CHECK(bp_calendar_new(2024, options, &winter) == BP_STATUS_OK);
+    CHECK(bp_calendar_month_count(winter) == 1);
+    bp_calendar_free(winter);
+
+    CHECK(bp_calendar_new(2024, "{\"periods\": []}", &winter) == BP_STATUS_INVALID_OPTIONS);
+    CHECK(winter == NULL);
+    CHECK(bp_calendar_new(2024, NULL, NULL) == BP_STATUS_NULL_POINTER);
+
+    return 0;
+}
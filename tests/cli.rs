@@ -60,3 +60,474 @@ fn correct_argument_supplied() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn compare_two_years() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.arg("compare")
+        .arg("--year")
+        .arg("2024")
+        .arg("--year")
+        .arg("2025");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Comparing 2024 -> 2025"))
+        .stdout(predicate::str::contains("January"));
+
+    Ok(())
+}
+
+#[test]
+fn compare_two_years_json() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args([
+        "compare", "--year", "2024", "--year", "2025", "--format", "json",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("{\"years\":[2024,2025]"));
+
+    Ok(())
+}
+
+#[test]
+fn compare_negative_years() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["compare", "--year", "-262143", "--year", "-5"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Comparing -262143 -> -5"));
+
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "-5"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"start_date\":\"-0005-01-01\""));
+
+    Ok(())
+}
+
+#[test]
+fn compare_a_single_year() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["compare", "--year", "2024"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("exactly two years"));
+
+    Ok(())
+}
+
+#[test]
+fn similar_years() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args([
+        "similar", "--year", "2026", "--from", "2000", "--to", "2030",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2015\n"))
+        .stdout(predicate::str::contains("2026\n").not());
+
+    Ok(())
+}
+
+#[test]
+fn similar_years_out_of_range() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["similar", "--year", "2026", "--to", "999999"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("out of the supported range"));
+
+    Ok(())
+}
+
+#[test]
+fn custom_periods_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args([
+        "--year",
+        "2024",
+        "--config",
+        "tests/fixtures/school_months.toml",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\":\"Winter term\""))
+        .stdout(predicate::str::contains("\"name\":\"July\"").not())
+        .stdout(predicate::str::contains("2024-07-01").not());
+
+    Ok(())
+}
+
+#[test]
+fn missing_config_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--config", "tests/fixtures/missing.toml"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "cannot read tests/fixtures/missing.toml",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn lookup_date() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--date", "2024-01-30"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "\"month\":\"February\",\"week\":{\"start_date\":\"2024-01-29\"",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn lookup_negative_date() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--date", "-0005-01-01"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "\"date\":\"-0005-01-01\",\"month\":\"January\"",
+    ));
+
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--date", "-0005-M01-W1"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"week_id\":\"-0005-M01-W1\""));
+
+    Ok(())
+}
+
+#[test]
+fn lookup_id() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--date", "2024-M02-W3"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "\"date\":\"2024-02-12\",\"month\":\"February\"",
+    ));
+
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--date", "2024-01-30"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "\"month_id\":\"2024-M02\",\"week_id\":\"2024-M02-W1\"",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn lookup_missing_id() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--date", "2024-M02-W6"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("no budget week 2024-M02-W6"));
+
+    Ok(())
+}
+
+#[test]
+fn lookup_today_with_timezone() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--tz", "Europe/Paris"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"date\":"));
+
+    Ok(())
+}
+
+#[test]
+fn lookup_with_unknown_timezone() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["lookup", "--tz", "Europe/Atlantis"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown timezone Europe/Atlantis"));
+
+    Ok(())
+}
+
+#[test]
+fn week_start_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--week-start", "Sun"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "{\"id\":\"2024-M01-W2\",\"start_date\":\"2024-01-07\",\"end_date\":\"2024-01-13\",\"start_day\":\"Sun\"}",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn wrong_ownership_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--ownership", "most"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown ownership rule most"));
+
+    Ok(())
+}
+
+#[test]
+fn validate_generated_plan() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["validate", "tests/fixtures/plan_2024.json"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("is a valid budget plan"));
+
+    Ok(())
+}
+
+#[test]
+fn validate_edited_plan() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["validate", "tests/fixtures/plan_2024_edited.json"]);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "January, week 5: the week belongs to February",
+        ))
+        .stderr(predicate::str::contains("1 violation(s) found"));
+
+    Ok(())
+}
+
+#[test]
+fn validate_with_another_ownership() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args([
+        "validate",
+        "tests/fixtures/plan_2024.json",
+        "--ownership",
+        "first-day",
+    ]);
+    cmd.assert().failure().stdout(predicate::str::contains(
+        "February, week 1: the week belongs to January",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn validate_missing_file() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["validate", "tests/fixtures/missing.json"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "cannot read tests/fixtures/missing.json",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn envelope_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--envelope"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "{\"schema_version\":1,\"tool_version\":",
+        ))
+        .stdout(predicate::str::contains(
+            "\"years\":[2024],\"week_start\":\"Mon\"",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn validate_plan_in_envelope() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    // Generated with --week-start Sun --ownership first-day, which the envelope remembers.
+    cmd.args(["validate", "tests/fixtures/plan_2024_envelope.json"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("is a valid budget plan"));
+
+    Ok(())
+}
+
+#[test]
+fn validate_plan_in_envelope_with_other_options() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args([
+        "validate",
+        "tests/fixtures/plan_2024_envelope.json",
+        "--week-start",
+        "Mon",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("violation(s) found"));
+
+    Ok(())
+}
+
+#[test]
+fn schema_printed() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.arg("schema");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"$schema\": \"https://json-schema.org/draft/2020-12/schema\"",
+        ))
+        .stdout(predicate::str::contains("\"Envelope\": {"));
+
+    Ok(())
+}
+
+#[test]
+fn strategy_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    // The first quarter of 2024 ends on Sunday March 31st.
+    cmd.args(["--year", "2024", "--strategy", "4-4-5"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "{\"id\":\"2024-M03-W5\",\"start_date\":\"2024-03-25\",\"end_date\":\"2024-03-31\",\"start_day\":\"Mon\"}]},{\"id\":\"2024-M04\",\"name\":\"April\"",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn wrong_strategy_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--strategy", "weekly"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown strategy weekly"));
+
+    Ok(())
+}
+
+#[test]
+fn format_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--format", "json"]);
+    cmd.assert().success().stdout(predicate::str::starts_with(
+        "[{\"id\":\"2024-M01\",\"name\":\"January\"",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn csv_format_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--format", "csv"]);
+    cmd.assert().success().stdout(predicate::str::starts_with(
+        "week_id,month,week,start_date,end_date,start_day,days,iso_week\n2024-M01-W1,January,1,2024-01-01,2024-01-07,Mon,7,2024-W01\n",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn csv_options_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args([
+        "--year",
+        "2024",
+        "--format",
+        "csv",
+        "--delimiter",
+        ";",
+        "--no-header",
+        "--quoting",
+        "always",
+    ]);
+    cmd.assert().success().stdout(predicate::str::starts_with(
+        "\"2024-M01-W1\";\"January\";\"1\";",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn wrong_delimiter_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--format", "csv", "--delimiter", "\""]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "invalid delimiter \"\\\"\", expected a character other than a double quote or a line break",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn ics_format_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--format", "ics", "--weeks"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("BEGIN:VCALENDAR\r\n"))
+        .stdout(predicate::str::contains("UID:2024-M02@budget-planner\r\n"))
+        .stdout(predicate::str::contains(
+            "UID:2024-M02-W3@budget-planner\r\n",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn markdown_format_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--format", "markdown", "--compact"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "| February 2024 | 2024-M02 | 5 | 2024-01-29 | 2024-03-03 | 35 |\n",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn wrong_format_supplied() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("budget_planner")?;
+
+    cmd.args(["--year", "2024", "--format", "xml"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "unknown format xml, expected json, csv, ics, markdown",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const HEADER: &str = "include/budget_planner.h";
+
+fn generate_header() -> String {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    let config = cbindgen::Config::from_root_or_default(crate_dir);
+    let mut header: Vec<u8> = Vec::new();
+
+    cbindgen::generate_with_config(crate_dir, config)
+        .expect("cannot generate the header")
+        .write(&mut header);
+
+    String::from_utf8(header).unwrap()
+}
+
+/// Run with `UPDATE_HEADER=1` to write the header after changing the C API.
+#[test]
+fn header_is_up_to_date() {
+    let path: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join(HEADER);
+    let header: String = generate_header();
+
+    if env::var_os("UPDATE_HEADER").is_some() {
+        fs::write(&path, &header).unwrap();
+    }
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap_or_default(),
+        header,
+        "{} is outdated, run `UPDATE_HEADER=1 cargo test --features ffi --test ffi`",
+        HEADER
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn c_program() -> Result<(), Box<dyn std::error::Error>> {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // The crate is only built as a cdylib on demand, in a target directory of its own to
+    // leave the one of the running build alone.
+    let target_dir: PathBuf = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cdylib");
+    let built = Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+        .args(["rustc", "--lib", "--crate-type", "cdylib"])
+        .args(["--no-default-features", "--features", "ffi"])
+        .arg("--manifest-path")
+        .arg(crate_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()?;
+    assert!(built.success());
+    let deps_dir: PathBuf = target_dir.join("debug");
+    let program: PathBuf = deps_dir.join("budget_planner_c_test");
+
+    let compiled = Command::new(env::var("CC").unwrap_or_else(|_| String::from("cc")))
+        .arg(crate_dir.join("tests/c/calendar.c"))
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg("-L")
+        .arg(&deps_dir)
+        .arg(format!("-Wl,-rpath,{}", deps_dir.display()))
+        .args(["-lbudget_planner", "-Wall", "-Werror", "-o"])
+        .arg(&program)
+        .status()?;
+    assert!(compiled.success());
+
+    // Cargo's library path can hold a build of the library without the C API, rely on the rpath.
+    let output = Command::new(&program)
+        .env_remove("LD_LIBRARY_PATH")
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.starts_with("January: 4 weeks\nFebruary: 5 weeks\n"));
+    assert_eq!(stdout.lines().count(), 12);
+
+    Ok(())
+}
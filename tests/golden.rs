@@ -0,0 +1,83 @@
+//! Plans of the default calendar in every output format, compared to the files of `tests/golden`.
+//!
+//! A changed output is written next to its golden file with a `.new` extension, to be
+//! reviewed with `diff`. Run with `UPDATE_GOLDEN=1` to accept every change.
+//!
+//! The version of the crate is written as `{version}`, so releasing doesn't change the files.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use budget_planner::{Calendar, Envelope, Renderers};
+
+/// A leap and a common year starting on each weekday, and two century years.
+const YEARS: [i32; 16] = [
+    2018, 2019, 2025, 2026, 2021, 2022, 2023, // common years, from Monday to Sunday
+    2024, 2008, 2020, 2004, 2016, 2000, 2012, // leap years, from Monday to Sunday
+    1900, 2100,
+];
+
+const VERSION: &str = concat!("budget_planner ", env!("CARGO_PKG_VERSION"));
+
+fn golden_path(format: &str, year: i32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format)
+        .join(format!("{}.{}", year, format))
+}
+
+fn render(renderers: &Renderers, format: &str, year: i32) -> String {
+    let calendar = Calendar::default();
+    let plan = Envelope::new(
+        vec![year],
+        &calendar,
+        calendar.year(year).unwrap().into_months(),
+    );
+    let mut out: Vec<u8> = Vec::new();
+
+    renderers
+        .get(format)
+        .unwrap()
+        .render(&plan, &mut out)
+        .unwrap();
+
+    String::from_utf8(out)
+        .unwrap()
+        .replace(VERSION, "budget_planner {version}")
+}
+
+#[test]
+fn plans_match_golden_files() {
+    let renderers = Renderers::default();
+    let update: bool = env::var_os("UPDATE_GOLDEN").is_some();
+    let mut changed: Vec<String> = Vec::new();
+
+    for format in renderers.names() {
+        for year in YEARS {
+            let path: PathBuf = golden_path(format, year);
+            let new_path: PathBuf = path.with_extension(format!("{}.new", format));
+            let output: String = render(&renderers, format, year);
+
+            if update {
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, &output).unwrap();
+            }
+
+            if fs::read_to_string(&path).ok().as_ref() == Some(&output) {
+                let _ = fs::remove_file(&new_path);
+            } else {
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&new_path, &output).unwrap();
+                changed.push(format!("diff {} {}", path.display(), new_path.display()));
+            }
+        }
+    }
+
+    assert!(
+        changed.is_empty(),
+        "{} plan(s) changed, review them with:\n{}\nand run `UPDATE_GOLDEN=1 cargo test --test golden` to accept them",
+        changed.len(),
+        changed.join("\n")
+    );
+}
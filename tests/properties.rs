@@ -0,0 +1,178 @@
+//! Invariants of the split, over every supported year and every calendar option.
+
+use budget_planner::{Calendar, CalendarBuilder, Month, Ownership, Period, Strategy as Split};
+use chrono::{Datelike, Month as ChronoMonth, NaiveDate, Weekday};
+use proptest::prelude::*;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+const OWNERSHIPS: [Ownership; 3] = [Ownership::Majority, Ownership::FirstDay, Ownership::LastDay];
+
+const SPLITS: [Split; 4] = [
+    Split::Monthly,
+    Split::FourFourFive,
+    Split::FourFiveFour,
+    Split::FiveFourFour,
+];
+
+/// Any supported year, the first and last ones and the century years being drawn more often.
+fn year() -> impl Strategy<Value = i32> {
+    let (first, last) = (NaiveDate::MIN.year(), NaiveDate::MAX.year());
+
+    prop_oneof![
+        4 => first..=last,
+        1 => Just(first),
+        1 => Just(last),
+        1 => (-20..=20i32).prop_map(|century| century * 100),
+        2 => 1900..=2100i32,
+    ]
+}
+
+/// The default periods, or consecutive calendar months cut into periods, some left out.
+fn periods() -> impl Strategy<Value = Option<Vec<Period>>> {
+    let custom = (any::<[bool; 11]>(), any::<[bool; 12]>()).prop_map(|(cuts, kept)| {
+        let mut groups: Vec<Vec<ChronoMonth>> = vec![vec![ChronoMonth::January]];
+        for (index, cut) in cuts.iter().enumerate() {
+            let month = ChronoMonth::try_from(index as u8 + 2).unwrap();
+            match cut {
+                true => groups.push(vec![month]),
+                false => groups.last_mut().unwrap().push(month),
+            }
+        }
+
+        // At least one period is required.
+        let keep_all: bool = !kept[..groups.len()].contains(&true);
+
+        Some(
+            groups
+                .into_iter()
+                .enumerate()
+                .filter(|(index, _)| keep_all || kept[*index])
+                .map(|(index, months)| Period::new(format!("Period {}", index + 1), months))
+                .collect(),
+        )
+    });
+
+    prop_oneof![Just(None), custom]
+}
+
+fn calendar() -> impl Strategy<Value = Calendar> {
+    (
+        proptest::sample::select(&WEEKDAYS[..]),
+        proptest::sample::select(&OWNERSHIPS[..]),
+        proptest::sample::select(&SPLITS[..]),
+        periods(),
+    )
+        .prop_map(|(week_start, ownership, split, periods)| {
+            let mut builder = CalendarBuilder::new()
+                .week_start(week_start)
+                .ownership(ownership)
+                .strategy(split);
+            if let Some(periods) = periods {
+                builder = builder.periods(periods);
+            }
+
+            builder.build().unwrap()
+        })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2048))]
+
+    #[test]
+    fn weeks_cover_the_year(year in year(), calendar in calendar()) {
+        let budget_year = calendar.year(year).unwrap();
+        let weeks = budget_year.weeks();
+
+        prop_assert_eq!(weeks[0].start_date(), NaiveDate::from_ymd_opt(year, 1, 1).unwrap());
+        prop_assert_eq!(
+            weeks.last().unwrap().end_date(),
+            NaiveDate::from_ymd_opt(year, 12, 31).unwrap()
+        );
+        for pair in weeks.windows(2) {
+            prop_assert_eq!(pair[0].end_date().succ_opt(), Some(pair[1].start_date()));
+        }
+    }
+
+    #[test]
+    fn weeks_start_on_their_start_day(year in year(), calendar in calendar()) {
+        let budget_year = calendar.year(year).unwrap();
+
+        for (index, week) in budget_year.weeks().iter().enumerate() {
+            prop_assert_eq!(week.start_day(), week.start_date().weekday());
+            prop_assert!((1..=7).contains(&week.days()));
+            if index > 0 {
+                prop_assert_eq!(week.start_day(), calendar.week_start());
+            }
+        }
+    }
+
+    #[test]
+    fn weeks_belong_to_one_month(year in year(), calendar in calendar()) {
+        let budget_year = calendar.year(year).unwrap();
+
+        for week in budget_year.weeks() {
+            let owners = budget_year
+                .months()
+                .iter()
+                .filter(|month| month.weeks().contains(week))
+                .count();
+            let month = calendar.strategy().month_of(week, &calendar);
+            let covered = calendar
+                .periods()
+                .iter()
+                .any(|period| period.months().contains(&month));
+
+            prop_assert_eq!(owners, usize::from(covered), "{:?}", week);
+        }
+
+        // Budget months follow the weeks of the year, each in a single block.
+        let mut weeks = budget_year.weeks().iter();
+        for month in budget_year.months() {
+            let first = month.weeks().first().unwrap();
+            prop_assert!(weeks.by_ref().any(|week| week == first));
+            for week in &month.weeks()[1..] {
+                prop_assert_eq!(weeks.next(), Some(week));
+            }
+        }
+    }
+
+    #[test]
+    fn months_have_4_to_5_weeks(year in year(), calendar in calendar()) {
+        let budget_year = calendar.year(year).unwrap();
+
+        for month in budget_year.months() {
+            let period = calendar
+                .periods()
+                .iter()
+                .find(|period| period.name() == month.name())
+                .unwrap();
+            let calendar_months = period.months().len();
+            // The 13 weeks quarters give the weeks past the 52nd to December.
+            let extra = usize::from(
+                calendar.strategy() != Split::Monthly
+                    && period.months().contains(&ChronoMonth::December),
+            );
+            let full_weeks = month.weeks().iter().filter(|week| week.days() == 7).count();
+
+            prop_assert!(month.weeks().len() >= 4 * calendar_months, "{}", month.name());
+            prop_assert!(full_weeks <= 5 * calendar_months + extra, "{}", month.name());
+        }
+    }
+
+    #[test]
+    fn lazy_months_match_years(year in year(), calendar in calendar()) {
+        let (years, lazy): (Vec<i32>, Vec<Month>) = calendar.months(year..=year).unwrap().unzip();
+
+        prop_assert!(years.iter().all(|month_year| *month_year == year));
+        prop_assert_eq!(lazy, calendar.year(year).unwrap().into_months());
+    }
+}
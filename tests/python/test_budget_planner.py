@@ -0,0 +1,92 @@
+"""Tests of the Python module, run with pytest once it is installed with `maturin develop`."""
+
+from datetime import date
+
+import pytest
+
+import budget_planner
+from budget_planner import Calendar
+
+
+def test_budget_year():
+    months = budget_planner.budget_year(2024)
+
+    assert [month.name for month in months][:3] == ["January", "February", "March"]
+    assert [len(month) for month in months] == [4, 5, 4, 4, 5, 4, 4, 5, 4, 5, 4, 5]
+
+
+def test_dates_are_native():
+    february = budget_planner.budget_year(2024)[1]
+
+    assert february.start_date == date(2024, 1, 29)
+    assert february.end_date == date(2024, 3, 3)
+    assert february.days == 35
+    assert date(2024, 2, 14) in february
+
+
+def test_weeks():
+    week = budget_planner.budget_year(2024)[0].weeks[0]
+
+    assert week.start_date == date(2024, 1, 1)
+    assert week.end_date == date(2024, 1, 7)
+    assert week.start_day == "Mon"
+    assert week.days == 7
+    assert repr(week) == "Week(2024-01-01, 2024-01-07)"
+
+
+def test_calendar_options():
+    calendar = Calendar(week_start="Sun", ownership="first-day")
+
+    weeks = calendar.year(2024)[0].weeks
+
+    assert weeks[1].start_day == "Sun"
+    assert weeks[1].start_date == date(2024, 1, 7)
+
+
+def test_strategy():
+    months = Calendar(strategy="4-4-5").year(2024)
+
+    assert [len(month) for month in months] == [4, 4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 6]
+    assert months[2].start_date == date(2024, 2, 26)
+
+
+def test_custom_periods():
+    calendar = Calendar(periods=[("Winter", [1, 2, 3]), ("Summer", [7, 8, 9])])
+
+    months = calendar.year(2024)
+
+    assert [month.name for month in months] == ["Winter", "Summer"]
+
+
+def test_lookup():
+    month, week = Calendar().lookup(date(2024, 1, 30))
+
+    assert month.name == "February"
+    assert week.start_date == date(2024, 1, 29)
+
+
+def test_lookup_outside_periods():
+    month, week = Calendar(periods=[("Winter", [1])]).lookup(date(2024, 7, 14))
+
+    assert month is None
+    assert date(2024, 7, 14) in week
+
+
+@pytest.mark.parametrize(
+    "options",
+    [
+        {"week_start": "Someday"},
+        {"ownership": "most"},
+        {"strategy": "4-4-4"},
+        {"periods": []},
+        {"periods": [("Winter", [13])]},
+    ],
+)
+def test_invalid_options(options):
+    with pytest.raises(ValueError):
+        Calendar(**options)
+
+
+def test_year_out_of_range():
+    with pytest.raises(ValueError):
+        budget_planner.budget_year(300_000)
@@ -0,0 +1,43 @@
+//! Runs the JavaScript API in a wasm runtime:
+//!
+//! ```sh
+//! cargo test --target wasm32-unknown-unknown --no-default-features --features wasm --test wasm
+//! ```
+//!
+//! `wasm-bindgen-test-runner` (from `cargo install wasm-bindgen-cli`) runs the tests with node.
+#![cfg(target_arch = "wasm32")]
+
+use budget_planner::wasm::{budget_months, budget_months_between};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn budget_months_of_a_year() {
+    let json = budget_months(2024, None).unwrap();
+
+    assert!(json.starts_with("[{\"id\":\"2024-M01\",\"name\":\"January\""));
+    assert_eq!(json.matches("\"name\"").count(), 12);
+}
+
+#[wasm_bindgen_test]
+fn budget_months_with_options() {
+    let options = String::from(r#"{"week_start": "Sun", "ownership": "first-day"}"#);
+
+    let json = budget_months(2024, Some(options)).unwrap();
+
+    assert!(json.contains(
+        "{\"id\":\"2024-M01-W2\",\"start_date\":\"2024-01-07\",\"end_date\":\"2024-01-13\",\"start_day\":\"Sun\"}"
+    ));
+}
+
+#[wasm_bindgen_test]
+fn budget_months_between_years() {
+    let json = budget_months_between(2023, 2025, None).unwrap();
+
+    assert_eq!(json.matches("\"year\":2024").count(), 12);
+    assert!(json.contains("\"id\":\"2024-M01\""));
+}
+
+#[wasm_bindgen_test]
+fn budget_months_with_invalid_options() {
+    assert!(budget_months(2024, Some(String::from("{\"periods\": []}"))).is_err());
+}